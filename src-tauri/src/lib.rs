@@ -10,9 +10,94 @@ use tokio::process::Command as TokioCommand;
 use sysinfo::System;
 // use tokio::time::{timeout, Duration};
 use futures_util::StreamExt;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
+use tokio_util::sync::CancellationToken;
 use regex::Regex;
 
+// Default size of the buffered chunks written to disk when saving an image
+const DEFAULT_SAVE_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+// Tauri event names only allow alphanumerics, '-', '/', ':' and '_'
+fn event_safe(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '/' | ':' | '_') { c } else { '_' })
+        .collect()
+}
+
+// Capabilities understood by the Linux kernel (see capabilities(7))
+const LINUX_CAPABILITIES: &[&str] = &[
+    "AUDIT_CONTROL", "AUDIT_READ", "AUDIT_WRITE", "BLOCK_SUSPEND", "BPF", "CHECKPOINT_RESTORE",
+    "CHOWN", "DAC_OVERRIDE", "DAC_READ_SEARCH", "FOWNER", "FSETID", "IPC_LOCK", "IPC_OWNER",
+    "KILL", "LEASE", "LINUX_IMMUTABLE", "MAC_ADMIN", "MAC_OVERRIDE", "MKNOD", "NET_ADMIN",
+    "NET_BIND_SERVICE", "NET_BROADCAST", "NET_RAW", "PERFMON", "SETFCAP", "SETGID", "SETPCAP",
+    "SETUID", "SYS_ADMIN", "SYS_BOOT", "SYS_CHROOT", "SYS_MODULE", "SYS_NICE", "SYS_PACCT",
+    "SYS_PTRACE", "SYS_RAWIO", "SYS_RESOURCE", "SYS_TIME", "SYS_TTY_CONFIG", "SYSLOG", "WAKE_ALARM",
+];
+
+// Logging drivers built into the Docker daemon
+const LOG_DRIVERS: &[&str] = &[
+    "none", "local", "json-file", "syslog", "journald", "gelf", "fluentd",
+    "awslogs", "splunk", "etwlogs", "gcplogs", "logentries",
+];
+
+// Normalize capability names ("net_admin", "CAP_NET_ADMIN") and reject unknown ones
+fn normalize_capabilities(capabilities: &[String]) -> Result<Vec<String>, String> {
+    capabilities
+        .iter()
+        .map(|capability| {
+            let upper = capability.trim().to_uppercase();
+            let name = upper.strip_prefix("CAP_").unwrap_or(&upper);
+            if name == "ALL" || LINUX_CAPABILITIES.contains(&name) {
+                Ok(name.to_string())
+            } else {
+                Err(format!("Unknown Linux capability: {}", capability))
+            }
+        })
+        .collect()
+}
+
+// Convert an RFC 3339 UTC timestamp as emitted by Docker ("2024-05-01T12:30:00.123456789Z")
+// into Unix seconds
+fn parse_rfc3339_secs(timestamp: &str) -> Option<i64> {
+    let (date, time) = timestamp.trim().split_once('T')?;
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    let time = time.trim_end_matches('Z');
+    let time = time.split(['+', '.']).next()?;
+    let mut time_parts = time.split(':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    let second: i64 = time_parts.next()?.parse().ok()?;
+
+    // Days since the epoch from a civil date (Howard Hinnant's algorithm)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second)
+}
+
+// Quote an argument for POSIX shells, leaving plain words untouched
+fn shell_quote(arg: &str) -> String {
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | ':' | '=' | '@' | ',' | '+' | '%'));
+
+    if is_plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
 // Function to strip ANSI escape sequences
 fn strip_ansi_codes(text: &str) -> String {
     // Regex to match ANSI escape sequences
@@ -32,6 +117,15 @@ pub struct ContainerInfo {
     pub project: Option<String>,
     pub service: Option<String>,
     pub labels: HashMap<String, String>,
+    // "healthy", "unhealthy" or "starting"; None when the container has no healthcheck
+    pub health: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PortOwners {
+    pub containers: Vec<ContainerInfo>,
+    // Set when no container publishes the port, e.g. when a host process holds it
+    pub note: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -62,7 +156,8 @@ pub struct VolumeInfo {
     pub labels: HashMap<String, String>,
     pub options: HashMap<String, String>,
     pub scope: String,
-    pub size: u64,
+    // None when sizes weren't computed (see `list_volumes_fast`), as opposed to zero bytes
+    pub size: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -119,7 +214,7 @@ pub struct TerminalOutput {
     pub success: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemStats {
     pub cpu_usage: f32,
     pub memory_used: u64,
@@ -131,971 +226,6768 @@ pub struct SystemStats {
     pub disk_used_gb: f64,
     pub disk_total_gb: f64,
     pub cpu_count: usize,
+    pub per_core_usage: Vec<f32>,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    // 1, 5 and 15 minute averages; None on Windows, which has no load average
+    pub load_average: Option<[f64; 3]>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContainerStats {
     pub id: String,
     pub name: String,
     pub cpu_percentage: f64,
+    // "aggregate": sum across cores, can exceed 100% (e.g. 250% = 2.5 cores busy)
+    // "per_cpu": averaged over online CPUs, always within 0-100%
+    pub cpu_mode: String,
     pub memory_usage: u64,
     pub memory_limit: u64,
     pub memory_percentage: f64,
+    pub memory_usage_human: String,
+    // "unlimited" when the container has no memory limit set
+    pub memory_limit_human: String,
     pub network_rx: u64,
     pub network_tx: u64,
+    // Per-interface traffic, only filled in when requested with `per_interface`
+    pub network_interfaces: Option<HashMap<String, InterfaceTraffic>>,
     pub block_read: u64,
     pub block_write: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DockerSystemInfo {
-    pub containers_running: usize,
-    pub containers_stopped: usize,
-    pub containers_total: usize,
-    pub images_total: usize,
-    pub volumes_total: usize,
-    pub networks_total: usize,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceTraffic {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
 }
 
-// Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
-#[tauri::command]
-fn greet(name: &str) -> String {
-    format!("Hello, {}! You've been greeted from Rust!", name)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkResult {
+    pub id: String,
+    pub success: bool,
+    pub message: String,
 }
 
-#[tauri::command]
-async fn list_containers() -> Result<Vec<ContainerInfo>, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
-
-    let options = Some(ListContainersOptions::<String> {
-        all: true,
-        ..Default::default()
-    });
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateContainerRequest {
+    pub image: String,
+    pub name: Option<String>,
+    pub user: Option<String>,
+    pub cap_add: Vec<String>,
+    pub cap_drop: Vec<String>,
+    pub log_driver: Option<String>,
+    pub log_options: HashMap<String, String>,
+    pub network_mode: Option<String>,
+    pub init: bool,
+    pub shm_size: Option<i64>,
+    pub ulimits: Vec<UlimitSpec>,
+    // Stop and remove an existing container with the same name instead of failing
+    pub replace_existing: bool,
+    // (host port, container port, protocol)
+    pub ports: Vec<(u16, u16, String)>,
+    // KEY=VALUE entries
+    pub env: Vec<String>,
+    // Bind mounts or named volumes as `source:target[:ro]`
+    pub volumes: Vec<String>,
+    // Start the container once it has been created
+    pub start: bool,
+}
 
-    let containers = docker
-        .list_containers(options)
-        .await
-        .map_err(|e| format!("Failed to list containers: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UlimitSpec {
+    pub name: String,
+    pub soft: i64,
+    pub hard: i64,
+}
 
-    let container_info: Vec<ContainerInfo> = containers
-        .into_iter()
-        .map(|container| {
-            let name = container
-                .names
-                .and_then(|names| names.first().cloned())
-                .unwrap_or_else(|| "unnamed".to_string())
-                .trim_start_matches('/')
-                .to_string();
-
-            let ports = container
-                .ports
-                .unwrap_or_default()
-                .into_iter()
-                .map(|port| PortInfo {
-                    private_port: port.private_port,
-                    public_port: port.public_port,
-                    r#type: port.typ.map(|t| t.to_string()).unwrap_or_else(|| "tcp".to_string()),
-                })
-                .collect();
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateNetworkRequest {
+    pub name: String,
+    pub driver: Option<String>,
+    pub internal: bool,
+    pub attachable: bool,
+    pub ipv6: bool,
+    pub labels: HashMap<String, String>,
+    pub options: HashMap<String, String>,
+}
 
-            // Extract labels from container
-            let labels = container.labels.unwrap_or_default();
-            
-            // Extract project name from Docker Compose labels
-            let project = labels.get("com.docker.compose.project")
-                .or_else(|| labels.get("com.docker.compose.project.name"))
-                .cloned();
-                
-            // Extract service name from Docker Compose labels
-            let service = labels.get("com.docker.compose.service")
-                .cloned();
-
-            ContainerInfo {
-                id: container.id.unwrap_or_else(|| "unknown".to_string()),
-                name,
-                image: container.image.unwrap_or_else(|| "unknown".to_string()),
-                status: container.status.unwrap_or_else(|| "unknown".to_string()),
-                state: container.state.unwrap_or_else(|| "unknown".to_string()),
-                created: container.created.unwrap_or(0),
-                ports,
-                project,
-                service,
-                labels,
-            }
-        })
-        .collect();
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatedContainer {
+    pub id: String,
+    pub warnings: Vec<String>,
+    // True when an existing container with the same name was removed first
+    pub replaced: bool,
+}
 
-    Ok(container_info)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectivityResult {
+    pub reachable: bool,
+    pub latency_ms: Option<f64>,
+    pub output: String,
 }
 
-#[tauri::command]
-async fn start_container(container_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardUpdate {
+    pub system: SystemStats,
+    pub docker: Option<DockerSystemInfo>,
+    pub top_containers: Vec<ContainerStats>,
+}
 
-    docker
-        .start_container(&container_id, None::<bollard::container::StartContainerOptions<String>>)
-        .await
-        .map_err(|e| format!("Failed to start container: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageUpdateStatus {
+    pub container_id: String,
+    pub name: String,
+    pub image: String,
+    pub current_digest: Option<String>,
+    pub latest_digest: Option<String>,
+    pub update_available: bool,
+    // The registry is throttling lookups; `latest_digest` is unknown until the backoff ends
+    pub rate_limited: bool,
+    // Why the latest digest couldn't be fetched
+    pub error: Option<String>,
+}
 
-    Ok(format!("Container {} started successfully", container_id))
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CategorizedLabels {
+    pub compose: HashMap<String, String>,
+    pub traefik: HashMap<String, String>,
+    pub custom: HashMap<String, String>,
+    pub system: HashMap<String, String>,
 }
 
-#[tauri::command]
-async fn stop_container(container_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerUptime {
+    pub started_at: String,
+    // None unless the container is running
+    pub uptime_secs: Option<u64>,
+    pub restart_count: i64,
+}
 
-    docker
-        .stop_container(&container_id, None)
-        .await
-        .map_err(|e| format!("Failed to stop container: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MountHealth {
+    pub source: String,
+    pub destination: String,
+    pub exists: bool,
+    pub r#type: String,
+}
 
-    Ok(format!("Container {} stopped successfully", container_id))
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnhealthyContainer {
+    pub id: String,
+    pub name: String,
+    pub health_status: String,
+    pub failing_streak: i64,
+    pub last_health_log: Option<String>,
 }
 
-#[tauri::command]
-async fn restart_container(container_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeResult {
+    pub container_id: String,
+    // True when the container was running and had to be stopped first
+    pub stopped: bool,
+    pub image_id: Option<String>,
+    pub image_removed: bool,
+    pub message: String,
+}
 
-    docker
-        .restart_container(&container_id, None)
-        .await
-        .map_err(|e| format!("Failed to restart container: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecDefaults {
+    pub working_dir: String,
+    pub shell: String,
+    pub user: String,
+}
 
-    Ok(format!("Container {} restarted successfully", container_id))
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecStatus {
+    pub exec_id: String,
+    pub container_id: Option<String>,
+    pub running: bool,
+    pub exit_code: Option<i64>,
 }
 
-#[tauri::command]
-async fn list_images() -> Result<Vec<ImageInfo>, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StoppedContainerDisk {
+    pub id: String,
+    pub name: String,
+    pub size_rw: u64,
+    pub finished_at: Option<String>,
+}
 
-    let options = Some(ListImagesOptions::<String> {
-        all: true,
-        ..Default::default()
-    });
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneSummary {
+    pub containers: Vec<String>,
+    pub images: Vec<String>,
+    pub networks: Vec<String>,
+    pub volumes: Vec<String>,
+    // Image sizes plus stopped containers' writable layers; volume contents are not included
+    pub estimated_bytes: u64,
+}
 
-    let images = docker
-        .list_images(options)
-        .await
-        .map_err(|e| format!("Failed to list images: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreparedPrune {
+    pub token: String,
+    pub summary: PruneSummary,
+}
 
-    let image_info: Vec<ImageInfo> = images
-        .into_iter()
-        .map(|image| ImageInfo {
-            id: image.id,
-            repo_tags: image.repo_tags,
-            repo_digests: image.repo_digests,
-            created: image.created,
-            size: image.size,
-            virtual_size: image.virtual_size.unwrap_or(0),
-            shared_size: image.shared_size,
-            labels: image.labels,
-        })
-        .collect();
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerPruneResult {
+    pub containers_deleted: Vec<String>,
+    pub space_reclaimed: u64,
+}
 
-    Ok(image_info)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerPort {
+    pub port: u16,
+    pub protocol: String,
+    pub published: bool,
+    pub host_binding: Option<String>,
 }
 
-#[tauri::command]
-async fn remove_image(image_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub stream: String,
+    pub message: String,
+}
 
-    // Use RemoveImageOptions to properly handle image removal
-    let options = Some(bollard::image::RemoveImageOptions {
-        force: false,
-        noprune: false,
-    });
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergedLogLine {
+    pub container_id: String,
+    pub container_name: String,
+    // Stable per-container index for picking a display color
+    pub color_index: usize,
+    pub timestamp: String,
+    pub stream: String,
+    pub message: String,
+}
 
-    let result = docker
-        .remove_image(&image_id, options, None)
-        .await
-        .map_err(|e| {
-            let error_msg = format!("Failed to remove image: {}", e);
-            // Check if it's a dependency error and suggest force removal
-            if error_msg.contains("conflict") || error_msg.contains("being used") {
-                format!("{}\n\nTip: The image might be in use by a container. Stop and remove dependent containers first, or use force removal.", error_msg)
-            } else {
-                error_msg
-            }
-        })?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogPage {
+    pub lines: Vec<LogEntry>,
+    pub next_cursor: Option<i64>,
+}
 
-    // The result is a vector of removal results
-    let removed_count = result.len();
-    
-    if removed_count > 0 {
-        Ok(format!("Image {} removed successfully ({} layers removed)", image_id, removed_count))
-    } else {
-        Ok(format!("Image {} removed successfully", image_id))
-    }
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DetailedResourceStats {
+    pub id: String,
+    pub cpu_total_usage: u64,
+    pub cpu_usage_in_kernelmode: u64,
+    pub cpu_usage_in_usermode: u64,
+    pub per_cpu_usage: Vec<u64>,
+    pub online_cpus: Option<u64>,
+    pub throttling_periods: u64,
+    pub throttled_periods: u64,
+    pub throttled_time: u64,
+    pub memory_usage: Option<u64>,
+    pub memory_max_usage: Option<u64>,
+    pub memory_limit: Option<u64>,
+    // cgroup v1 "cache"/"rss", or their cgroup v2 equivalents "file"/"anon"
+    pub memory_cache: Option<u64>,
+    pub memory_rss: Option<u64>,
+    pub pids_current: Option<u64>,
 }
 
-#[tauri::command]
-async fn force_remove_image(image_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResourceRecommendation {
+    pub suggested_memory: u64,
+    pub suggested_cpus: f64,
+    pub basis: String,
+}
 
-    // Use force removal for stubborn images
-    let options = Some(bollard::image::RemoveImageOptions {
-        force: true,
-        noprune: false,
-    });
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerEvent {
+    pub r#type: String,
+    pub action: String,
+    pub actor_id: String,
+    pub attributes: HashMap<String, String>,
+    pub time: i64,
+}
 
-    let result = docker
-        .remove_image(&image_id, options, None)
-        .await
-        .map_err(|e| format!("Failed to force remove image: {}", e))?;
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RegistryAuth {
+    pub username: String,
+    pub password: String,
+    pub server_address: Option<String>,
+    pub identity_token: Option<String>,
+}
 
-    let removed_count = result.len();
-    
-    if removed_count > 0 {
-        Ok(format!("Image {} force removed successfully ({} layers removed)", image_id, removed_count))
-    } else {
-        Ok(format!("Image {} force removed successfully", image_id))
+// Keeps secrets out of `{:?}` output
+impl std::fmt::Debug for RegistryAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegistryAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("server_address", &self.server_address)
+            .field("identity_token", &self.identity_token.as_ref().map(|_| "<redacted>"))
+            .finish()
     }
 }
 
-#[tauri::command]
-async fn list_volumes() -> Result<Vec<VolumeInfo>, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagePushEvent {
+    pub status: Option<String>,
+    pub progress: Option<String>,
+}
 
-    let options = ListVolumesOptions::<String> {
-        ..Default::default()
-    };
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagePullEvent {
+    pub id: Option<String>,
+    pub status: Option<String>,
+    pub current: Option<i64>,
+    pub total: Option<i64>,
+}
 
-    let volumes_response = docker
-        .list_volumes(Some(options))
-        .await
-        .map_err(|e| format!("Failed to list volumes: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagePullProgress {
+    pub image: String,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    pub percentage: f64,
+}
 
-    // Get all volume sizes using Docker system df
-    let volume_sizes = get_all_volume_sizes().await.unwrap_or_default();
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageAge {
+    pub id: String,
+    pub repo_tags: Vec<String>,
+    pub created: i64,
+    pub age_days: u64,
+    pub in_use: bool,
+}
 
-    let volume_info: Vec<VolumeInfo> = volumes_response
-        .volumes
-        .unwrap_or_default()
-        .into_iter()
-        .map(|volume| {
-            // Get size from Docker system df results
-            let size = volume_sizes.get(&volume.name).copied().unwrap_or(0);
-            
-            VolumeInfo {
-                name: volume.name,
-                driver: volume.driver,
-                mountpoint: volume.mountpoint,
-                created_at: volume.created_at,
-                labels: volume.labels,
-                options: volume.options,
-                scope: volume.scope.map(|s| s.to_string()).unwrap_or_else(|| "local".to_string()),
-                size,
-            }
-        })
-        .collect();
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuildContextSize {
+    pub total_bytes: u64,
+    pub file_count: u64,
+    pub largest_files: Vec<(String, u64)>,
+}
 
-    Ok(volume_info)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageBuildEvent {
+    // "stream", "status", "error" or "image_id"
+    pub kind: String,
+    pub message: String,
 }
 
-#[tauri::command]
-async fn create_volume(volume_name: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageRemoveEvent {
+    pub untagged: Option<String>,
+    pub deleted: Option<String>,
+}
 
-    let config = bollard::volume::CreateVolumeOptions {
-        name: volume_name.clone(),
-        driver: "local".to_string(),
-        ..Default::default()
-    };
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSaveProgress {
+    pub image: String,
+    pub bytes_written: u64,
+    pub throughput_mbps: f64,
+    pub done: bool,
+    // Set on the final event when the save failed
+    pub error: Option<String>,
+}
 
-    docker
-        .create_volume(config)
-        .await
-        .map_err(|e| format!("Failed to create volume: {}", e))?;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerExportProgress {
+    pub container: String,
+    pub bytes_written: u64,
+    pub done: bool,
+}
 
-    Ok(format!("Volume {} created successfully", volume_name))
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerSystemInfo {
+    pub containers_running: usize,
+    pub containers_stopped: usize,
+    pub containers_total: usize,
+    pub images_total: usize,
+    pub volumes_total: usize,
+    pub networks_total: usize,
 }
 
-#[tauri::command]
-async fn remove_volume(volume_name: String) -> Result<String, String> {
-    println!("Attempting to remove volume: {}", volume_name);
-    
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| {
-            let error_msg = format!("Failed to connect to Docker: {}", e);
-            println!("Docker connection error: {}", error_msg);
-            error_msg
-        })?;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SwarmNodeInfo {
+    pub id: String,
+    pub hostname: String,
+    pub role: String,
+    pub availability: String,
+    pub status: String,
+    pub engine_version: Option<String>,
+    pub leader: bool,
+}
 
-    println!("Connected to Docker, removing volume: {}", volume_name);
-    
-    docker
-        .remove_volume(&volume_name, None)
+// API version pinned by the user via `set_api_version`; None means negotiate automatically
+static PINNED_API_VERSION: std::sync::RwLock<Option<bollard::ClientVersion>> = std::sync::RwLock::new(None);
+
+// Request timeout bollard uses for its default connections
+const DOCKER_TIMEOUT_SECS: u64 = 120;
+
+fn docker_host() -> String {
+    std::env::var("DOCKER_HOST").unwrap_or_else(|_| {
+        if cfg!(windows) {
+            "npipe:////./pipe/docker_engine".to_string()
+        } else {
+            "unix:///var/run/docker.sock".to_string()
+        }
+    })
+}
+
+fn parse_api_version(version: &str) -> Result<bollard::ClientVersion, String> {
+    let trimmed = version.trim().trim_start_matches('v');
+    let (major, minor) = trimmed
+        .split_once('.')
+        .ok_or_else(|| format!("Invalid API version '{}'. Expected a version like 1.41", version))?;
+
+    Ok(bollard::ClientVersion {
+        major_version: major.parse().map_err(|_| format!("Invalid API major version in '{}'", version))?,
+        minor_version: minor.parse().map_err(|_| format!("Invalid API minor version in '{}'", version))?,
+    })
+}
+
+async fn connect_docker() -> Result<Docker, String> {
+    let pinned = PINNED_API_VERSION.read().ok().and_then(|version| *version);
+
+    match pinned {
+        Some(version) => Docker::connect_with_local(&docker_host(), DOCKER_TIMEOUT_SECS, &version)
+            .map_err(|e| format!("Failed to connect to Docker: {}", e)),
+        None => Docker::connect_with_socket_defaults()
+            .map_err(|e| format!("Failed to connect to Docker: {}", e))?
+            // Drop to the daemon's API version when it is older than bollard's default
+            .negotiate_version()
+            .await
+            .map_err(|e| format!("Failed to negotiate Docker API version: {}", e)),
+    }
+}
+
+// How long a shared client is trusted before it is pinged again
+const DOCKER_CLIENT_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+// One Docker client shared by every command. It is re-validated with a ping at most every
+// DOCKER_CLIENT_CHECK_INTERVAL and rebuilt when that fails, so a restarted daemon (or a new
+// pinned API version) is picked up without relaunching the app.
+#[derive(Default)]
+pub struct DockerClient(tokio::sync::Mutex<Option<(Docker, std::time::Instant)>>);
+
+impl DockerClient {
+    async fn get(&self) -> Result<Docker, String> {
+        let stale = match self.0.lock().await.as_ref() {
+            Some((docker, checked_at)) if checked_at.elapsed() < DOCKER_CLIENT_CHECK_INTERVAL => {
+                return Ok(docker.clone());
+            }
+            Some((docker, _)) => Some(docker.clone()),
+            None => None,
+        };
+
+        // Ping without holding the lock so other commands aren't queued behind a slow daemon,
+        // and only a successful ping pushes the next check out
+        if let Some(docker) = stale {
+            if docker.ping().await.is_ok() {
+                if let Some((_, checked_at)) = self.0.lock().await.as_mut() {
+                    *checked_at = std::time::Instant::now();
+                }
+                return Ok(docker);
+            }
+        }
+
+        let docker = connect_docker().await?;
+        *self.0.lock().await = Some((docker.clone(), std::time::Instant::now()));
+        Ok(docker)
+    }
+
+    async fn reset(&self) {
+        *self.0.lock().await = None;
+    }
+}
+
+#[tauri::command]
+async fn set_api_version(version: String, docker_client: tauri::State<'_, DockerClient>) -> Result<String, String> {
+    if version.trim().is_empty() || version.trim().eq_ignore_ascii_case("auto") {
+        if let Ok(mut pinned) = PINNED_API_VERSION.write() {
+            *pinned = None;
+        }
+        docker_client.reset().await;
+        return Ok("Docker API version will be negotiated automatically".to_string());
+    }
+
+    let client_version = parse_api_version(&version)?;
+
+    let docker = Docker::connect_with_local(&docker_host(), DOCKER_TIMEOUT_SECS, &client_version)
+        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+
+    // Make sure the daemon actually accepts the requested version before pinning it
+    let server = docker
+        .version()
         .await
-        .map_err(|e| {
-            let error_msg = format!("Failed to remove volume '{}': {}", volume_name, e);
-            println!("Volume removal error: {}", error_msg);
-            error_msg
-        })?;
+        .map_err(|e| format!("Docker rejected API version {}: {}", version, e))?;
 
-    let success_msg = format!("Volume {} removed successfully", volume_name);
-    println!("{}", success_msg);
-    Ok(success_msg)
+    if let Ok(mut pinned) = PINNED_API_VERSION.write() {
+        *pinned = Some(client_version);
+    }
+    docker_client.reset().await;
+
+    Ok(format!(
+        "Docker API version set to {}.{} (daemon supports up to {})",
+        client_version.major_version,
+        client_version.minor_version,
+        server.api_version.unwrap_or_else(|| "unknown".to_string())
+    ))
 }
 
+// Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
-async fn get_volume_size(volume_name: String) -> Result<u64, String> {
-    // Get all volume sizes using Docker system df
-    let volume_sizes = get_all_volume_sizes().await?;
+fn greet(name: &str) -> String {
+    format!("Hello, {}! You've been greeted from Rust!", name)
+}
+
+// Reads the health suffix the daemon appends to the status, e.g. "Up 5 minutes (healthy)"
+// or "Up 3 seconds (health: starting)"
+fn health_from_status(status: &str) -> Option<String> {
+    let suffix = status.rsplit_once('(')?.1.strip_suffix(')')?;
+    let health = suffix.strip_prefix("health: ").unwrap_or(suffix);
+
+    ["healthy", "unhealthy", "starting"]
+        .contains(&health)
+        .then(|| health.to_string())
+}
+
+fn container_info_from_summary(container: bollard::models::ContainerSummary) -> ContainerInfo {
+    let name = container
+        .names
+        .and_then(|names| names.first().cloned())
+        .unwrap_or_else(|| "unnamed".to_string())
+        .trim_start_matches('/')
+        .to_string();
+
+    let ports = container
+        .ports
+        .unwrap_or_default()
+        .into_iter()
+        .map(|port| PortInfo {
+            private_port: port.private_port,
+            public_port: port.public_port,
+            r#type: port.typ.map(|t| t.to_string()).unwrap_or_else(|| "tcp".to_string()),
+        })
+        .collect();
+
+    // Extract labels from container
+    let labels = container.labels.unwrap_or_default();
     
-    // Return the size for the specific volume
-    Ok(volume_sizes.get(&volume_name).copied().unwrap_or(0))
+    // Extract project name from Docker Compose labels
+    let project = labels.get("com.docker.compose.project")
+        .or_else(|| labels.get("com.docker.compose.project.name"))
+        .cloned();
+        
+    // Extract service name from Docker Compose labels
+    let service = labels.get("com.docker.compose.service")
+        .cloned();
+
+    let status = container.status.unwrap_or_else(|| "unknown".to_string());
+    let health = health_from_status(&status);
+
+    ContainerInfo {
+        id: container.id.unwrap_or_else(|| "unknown".to_string()),
+        name,
+        image: container.image.unwrap_or_else(|| "unknown".to_string()),
+        status,
+        state: container.state.unwrap_or_else(|| "unknown".to_string()),
+        created: container.created.unwrap_or(0),
+        ports,
+        project,
+        service,
+        labels,
+        health,
+    }
 }
 
-async fn get_all_volume_sizes() -> Result<HashMap<String, u64>, String> {
-    // Use Docker's system df -v command to get accurate volume sizes
-    let mut cmd = TokioCommand::new("docker");
-    cmd.args(&["system", "df", "-v"]);
-    cmd.stdout(Stdio::piped());
-    cmd.stderr(Stdio::piped());
+async fn fetch_containers(docker: &Docker) -> Result<Vec<ContainerInfo>, String> {
+    fetch_containers_filtered(docker, HashMap::new(), true).await
+}
 
-    let output = cmd.output().await
-        .map_err(|e| format!("Failed to execute docker system df: {}", e))?;
+// `filters` are passed straight through to the daemon, e.g. {"health": ["unhealthy"]}.
+// `all` includes stopped containers.
+async fn fetch_containers_filtered(
+    docker: &Docker,
+    filters: HashMap<String, Vec<String>>,
+    all: bool,
+) -> Result<Vec<ContainerInfo>, String> {
+    let options = Some(ListContainersOptions::<String> {
+        all,
+        filters,
+        ..Default::default()
+    });
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Docker system df failed: {}", stderr));
+    let containers = docker
+        .list_containers(options)
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let mut containers: Vec<ContainerInfo> = containers.into_iter().map(container_info_from_summary).collect();
+
+    // A paused container's status reads "(Paused)" and hides its health, so ask the daemon
+    let paused = containers
+        .iter_mut()
+        .filter(|container| container.state == "paused" && container.health.is_none());
+    let lookups = paused.map(|container| async move {
+        if let Ok(inspect) = docker.inspect_container(&container.id, None).await {
+            container.health = inspect
+                .state
+                .and_then(|state| state.health)
+                .and_then(|health| health.status)
+                .map(|status| status.to_string())
+                .filter(|status| !status.is_empty() && status != "none");
+        }
+    });
+    futures_util::future::join_all(lookups).await;
+
+    Ok(containers)
+}
+
+// States accepted by the daemon's `status` filter
+const CONTAINER_STATUSES: [&str; 7] = ["created", "restarting", "running", "removing", "paused", "exited", "dead"];
+
+#[tauri::command]
+async fn list_containers(
+    health_filter: Option<String>,
+    name_filter: Option<String>,
+    status_filter: Option<Vec<String>>,
+    label_filters: Option<HashMap<String, String>>,
+    all: Option<bool>,
+    stats_cache: tauri::State<'_, StatsCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ContainerInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let mut filters: HashMap<String, Vec<String>> = HashMap::new();
+    if let Some(health) = health_filter.map(|health| health.trim().to_lowercase()).filter(|health| !health.is_empty()) {
+        if !["healthy", "unhealthy", "starting", "none"].contains(&health.as_str()) {
+            return Err(format!("Invalid health filter '{}'. Expected healthy, unhealthy, starting or none", health));
+        }
+        filters.insert("health".to_string(), vec![health]);
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut volume_sizes = HashMap::new();
-    let mut in_volumes_section = false;
+    // The daemon matches names as a substring
+    if let Some(name) = name_filter.map(|name| name.trim().to_string()).filter(|name| !name.is_empty()) {
+        filters.insert("name".to_string(), vec![name]);
+    }
 
-    // Parse the output line by line
-    for line in stdout.lines() {
-        let trimmed = line.trim();
-        
-        // Look for the volumes section header
-        if trimmed.starts_with("VOLUME NAME") {
-            in_volumes_section = true;
+    let statuses: Vec<String> = status_filter
+        .unwrap_or_default()
+        .into_iter()
+        .map(|status| status.trim().to_lowercase())
+        .filter(|status| !status.is_empty())
+        .collect();
+    if let Some(status) = statuses.iter().find(|status| !CONTAINER_STATUSES.contains(&status.as_str())) {
+        return Err(format!("Invalid status filter '{}'. Expected one of: {}", status, CONTAINER_STATUSES.join(", ")));
+    }
+    if !statuses.is_empty() {
+        filters.insert("status".to_string(), statuses);
+    }
+
+    // Each entry must match, e.g. {"com.docker.compose.project": "shop"}; an empty value
+    // only requires the label to be present
+    let labels: Vec<String> = label_filters
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(key, _)| !key.trim().is_empty())
+        .map(|(key, value)| if value.is_empty() { key } else { format!("{}={}", key, value) })
+        .collect();
+    if !labels.is_empty() {
+        filters.insert("label".to_string(), labels);
+    }
+
+    let containers = fetch_containers_filtered(&docker, filters, all.unwrap_or(true)).await?;
+
+    // Warm the stats cache so opening a container right after the list loads is instant
+    let running_ids: Vec<String> = containers
+        .iter()
+        .filter(|container| container.state == "running")
+        .map(|container| container.id.clone())
+        .collect();
+    prefetch_container_stats(docker, running_ids, stats_cache.inner().clone());
+
+    Ok(containers)
+}
+
+// Compose containers whose compose file(s) no longer exist on disk, typically left behind
+// when a project directory was deleted without `compose down`
+#[tauri::command]
+async fn orphaned_compose_containers(
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ContainerInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let containers = fetch_containers(&docker).await?;
+    let mut orphans = Vec::new();
+
+    for container in containers {
+        if container.project.is_none() {
             continue;
         }
-        
-        // Skip empty lines or if not in volumes section
-        if !in_volumes_section || trimmed.is_empty() {
+
+        // Without the label (older Compose versions) there's nothing to check against
+        let config_files: Vec<String> = match container.labels.get("com.docker.compose.project.config_files") {
+            Some(files) => files
+                .split(',')
+                .map(|file| file.trim().to_string())
+                .filter(|file| !file.is_empty())
+                .collect(),
+            None => continue,
+        };
+        if config_files.is_empty() {
             continue;
         }
-        
-        // Stop if we hit another section
-        if trimmed.starts_with("Build Cache") || trimmed.starts_with("REPOSITORY") {
-            break;
-        }
-        
-        // Parse volume line: VOLUME_NAME   LINKS   SIZE
-        let parts: Vec<&str> = trimmed.split_whitespace().collect();
-        if parts.len() >= 3 {
-            let volume_name = parts[0];
-            let size_str = parts[2]; // SIZE is the third column
-            
-            // Parse size string (e.g., "1.5GB", "256MB", "0B")
-            if let Ok(size_bytes) = parse_docker_size(size_str) {
-                volume_sizes.insert(volume_name.to_string(), size_bytes);
+
+        let mut any_exists = false;
+        for file in &config_files {
+            if tokio::fs::try_exists(file).await.unwrap_or(false) {
+                any_exists = true;
+                break;
             }
         }
+
+        if !any_exists {
+            orphans.push(container);
+        }
     }
 
-    Ok(volume_sizes)
+    Ok(orphans)
 }
 
-fn parse_docker_size(size_str: &str) -> Result<u64, String> {
-    if size_str == "0B" || size_str == "0" {
-        return Ok(0);
+// Which container(s) publish `host_port`, for diagnosing "port is already allocated"
+#[tauri::command]
+async fn find_by_port(host_port: u16, docker_client: tauri::State<'_, DockerClient>) -> Result<PortOwners, String> {
+    let docker = docker_client.get().await?;
+
+    let containers: Vec<ContainerInfo> = fetch_containers(&docker)
+        .await?
+        .into_iter()
+        .filter(|container| container.ports.iter().any(|port| port.public_port == Some(host_port)))
+        .collect();
+
+    if !containers.is_empty() {
+        return Ok(PortOwners { containers, note: None });
     }
 
-    let size_str = size_str.trim();
-    let (number_part, unit_part) = if let Some(pos) = size_str.find(|c: char| c.is_alphabetic()) {
-        (&size_str[..pos], &size_str[pos..])
+    // Only meaningful when Docker binds ports on this machine (not a remote DOCKER_HOST)
+    let note = match tokio::net::TcpListener::bind(("0.0.0.0", host_port)).await {
+        Ok(_) => format!("No container publishes port {} and it is free on this host", host_port),
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+            format!("No container publishes port {}; it is held by a non-Docker process on this host", host_port)
+        }
+        Err(_) => format!("No container publishes port {}", host_port),
+    };
+
+    Ok(PortOwners {
+        containers,
+        note: Some(note),
+    })
+}
+
+fn format_ports(ports: &[PortInfo]) -> String {
+    ports
+        .iter()
+        .map(|port| match port.public_port {
+            Some(public_port) => format!("{}->{}/{}", public_port, port.private_port, port.r#type),
+            None => format!("{}/{}", port.private_port, port.r#type),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
     } else {
-        (size_str, "B")
+        value.to_string()
+    }
+}
+
+#[tauri::command]
+async fn export_containers(
+    format: String,
+    output_path: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    let containers = fetch_containers(&docker).await?;
+
+    let contents = match format.to_lowercase().as_str() {
+        "json" => serde_json::to_string_pretty(&containers)
+            .map_err(|e| format!("Failed to serialize containers: {}", e))?,
+        "csv" => {
+            let mut csv = String::from("name,image,state,status,ports,project\n");
+            for container in &containers {
+                let row = [
+                    csv_field(&container.name),
+                    csv_field(&container.image),
+                    csv_field(&container.state),
+                    csv_field(&container.status),
+                    csv_field(&format_ports(&container.ports)),
+                    csv_field(container.project.as_deref().unwrap_or("")),
+                ];
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            csv
+        }
+        _ => return Err(format!("Unsupported export format '{}'. Expected 'json' or 'csv'", format)),
     };
 
-    let number: f64 = number_part.parse()
-        .map_err(|_| format!("Invalid number in size: {}", size_str))?;
+    tokio::fs::write(&output_path, contents)
+        .await
+        .map_err(|e| format!("Failed to write export file '{}': {}", output_path, e))?;
 
-    let multiplier = match unit_part.to_uppercase().as_str() {
-        "B" => 1,
-        "KB" => 1024,
-        "MB" => 1024 * 1024,
-        "GB" => 1024 * 1024 * 1024,
-        "TB" => 1024_u64.pow(4),
-        _ => return Err(format!("Unknown size unit: {}", unit_part)),
+    Ok(format!("Exported {} containers to {}", containers.len(), output_path))
+}
+
+fn container_host_config(request: &CreateContainerRequest) -> Result<bollard::models::HostConfig, String> {
+    let cap_add = normalize_capabilities(&request.cap_add)?;
+    let cap_drop = normalize_capabilities(&request.cap_drop)?;
+
+    let log_config = match &request.log_driver {
+        Some(driver) if !LOG_DRIVERS.contains(&driver.as_str()) => {
+            return Err(format!("Unknown log driver '{}'. Expected one of: {}", driver, LOG_DRIVERS.join(", ")));
+        }
+        Some(driver) => Some(bollard::models::HostConfigLogConfig {
+            typ: Some(driver.clone()),
+            config: if request.log_options.is_empty() { None } else { Some(request.log_options.clone()) },
+        }),
+        None if !request.log_options.is_empty() => {
+            return Err("Log options require a log driver to be set".to_string());
+        }
+        None => None,
     };
 
-    Ok((number * multiplier as f64) as u64)
+    if let Some(shm_size) = request.shm_size {
+        if shm_size <= 0 {
+            return Err("Shared memory size must be greater than zero".to_string());
+        }
+    }
+
+    let mut ulimits = Vec::with_capacity(request.ulimits.len());
+    for ulimit in &request.ulimits {
+        if ulimit.name.trim().is_empty() {
+            return Err("Ulimit name cannot be empty".to_string());
+        }
+        // -1 means unlimited
+        if ulimit.hard != -1 && ulimit.soft > ulimit.hard {
+            return Err(format!("Ulimit '{}' has a soft limit above its hard limit", ulimit.name));
+        }
+        ulimits.push(bollard::models::ResourcesUlimits {
+            name: Some(ulimit.name.trim().to_string()),
+            soft: Some(ulimit.soft),
+            hard: Some(ulimit.hard),
+        });
+    }
+
+    let mut port_bindings: HashMap<String, Option<Vec<bollard::models::PortBinding>>> = HashMap::new();
+    for (key, binding) in container_port_bindings(&request.ports)? {
+        // The same container port may be published on several host ports
+        port_bindings.entry(key).or_insert_with(|| Some(Vec::new())).get_or_insert_with(Vec::new).push(binding);
+    }
+
+    for volume in &request.volumes {
+        let parts: Vec<&str> = volume.split(':').collect();
+        let valid = match parts.as_slice() {
+            [source, target] => !source.is_empty() && target.starts_with('/'),
+            [source, target, mode] => !source.is_empty() && target.starts_with('/') && !mode.is_empty(),
+            _ => false,
+        };
+        if !valid {
+            return Err(format!("Invalid volume mount '{}'. Expected source:/container/path[:ro]", volume));
+        }
+    }
+
+    Ok(bollard::models::HostConfig {
+        cap_add: if cap_add.is_empty() { None } else { Some(cap_add) },
+        cap_drop: if cap_drop.is_empty() { None } else { Some(cap_drop) },
+        log_config,
+        network_mode: request.network_mode.clone().filter(|mode| !mode.trim().is_empty()),
+        // Runs tini as PID 1 so zombies get reaped and signals are forwarded
+        init: if request.init { Some(true) } else { None },
+        shm_size: request.shm_size,
+        ulimits: if ulimits.is_empty() { None } else { Some(ulimits) },
+        port_bindings: if port_bindings.is_empty() { None } else { Some(port_bindings) },
+        binds: if request.volumes.is_empty() { None } else { Some(request.volumes.clone()) },
+        ..Default::default()
+    })
+}
+
+// Validates `(host, container, protocol)` triples and turns them into Docker's
+// `port/proto` keys with their host bindings
+fn container_port_bindings(ports: &[(u16, u16, String)]) -> Result<Vec<(String, bollard::models::PortBinding)>, String> {
+    ports
+        .iter()
+        .map(|(host_port, container_port, protocol)| {
+            let protocol = protocol.trim().to_lowercase();
+            let protocol = if protocol.is_empty() { "tcp".to_string() } else { protocol };
+            if !matches!(protocol.as_str(), "tcp" | "udp" | "sctp") {
+                return Err(format!("Unsupported port protocol '{}'. Expected tcp, udp or sctp", protocol));
+            }
+            if *host_port == 0 || *container_port == 0 {
+                return Err("Port numbers must be between 1 and 65535".to_string());
+            }
+            Ok((
+                format!("{}/{}", container_port, protocol),
+                bollard::models::PortBinding {
+                    host_ip: None,
+                    host_port: Some(host_port.to_string()),
+                },
+            ))
+        })
+        .collect()
 }
 
+// Bytes written between `container-export-progress-*` events
+const EXPORT_PROGRESS_INTERVAL: u64 = 4 * 1024 * 1024;
+
+// `docker export`: the container's current filesystem as a flat tar, without image layers
 #[tauri::command]
-async fn list_networks() -> Result<Vec<NetworkInfo>, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn export_container_fs(
+    container_id: String,
+    output_path: String,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    use tokio::io::AsyncWriteExt;
 
-    let options = Some(ListNetworksOptions::<String> {
-        ..Default::default()
-    });
+    let docker = docker_client.get().await?;
+    let event_name = format!("container-export-progress-{}", event_safe(&container_id));
 
-    let networks = docker
-        .list_networks(options)
+    let mut file = tokio::fs::File::create(&output_path)
         .await
-        .map_err(|e| format!("Failed to list networks: {}", e))?;
+        .map_err(|e| format!("Failed to create output file '{}': {}", output_path, e))?;
+
+    let mut bytes_written: u64 = 0;
+    let mut last_reported: u64 = 0;
+    let mut export_stream = docker.export_container(&container_id);
+
+    while let Some(chunk) = export_stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Failed to export container: {}", e))?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("Failed to write container archive: {}", e))?;
+        bytes_written += chunk.len() as u64;
+
+        if bytes_written - last_reported >= EXPORT_PROGRESS_INTERVAL {
+            last_reported = bytes_written;
+            let _ = app_handle.emit(&event_name, ContainerExportProgress {
+                container: container_id.clone(),
+                bytes_written,
+                done: false,
+            });
+        }
+    }
 
-    let network_info: Vec<NetworkInfo> = networks
-        .into_iter()
-        .map(|network| {
-            let ipam_config = network
-                .ipam
-                .as_ref()
-                .and_then(|ipam| ipam.config.as_ref())
-                .map(|configs| {
-                    configs
-                        .iter()
-                        .map(|config| IpamConfig {
-                            subnet: config.subnet.clone(),
-                            gateway: config.gateway.clone(),
-                            ip_range: config.ip_range.clone(),
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
+    file.flush()
+        .await
+        .map_err(|e| format!("Failed to flush container archive: {}", e))?;
 
-            let containers = network
-                .containers
-                .unwrap_or_default()
-                .into_iter()
-                .map(|(key, container)| {
-                    (
-                        key,
-                        NetworkContainer {
-                            name: container.name,
-                            endpoint_id: container.endpoint_id,
+    let _ = app_handle.emit(&event_name, ContainerExportProgress {
+        container: container_id.clone(),
+        bytes_written,
+        done: true,
+    });
+
+    Ok(format!("Container {} exported to {} ({} bytes)", container_id, output_path, bytes_written))
+}
+
+#[tauri::command]
+async fn create_container(
+    request: CreateContainerRequest,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<CreatedContainer, String> {
+    if request.image.trim().is_empty() {
+        return Err("An image is required to create a container".to_string());
+    }
+
+    let host_config = container_host_config(&request)?;
+
+    if let Some(entry) = request.env.iter().find(|entry| !entry.contains('=') || entry.starts_with('=')) {
+        return Err(format!("Invalid environment variable '{}'. Expected KEY=VALUE", entry));
+    }
+
+    let exposed_ports: HashMap<String, HashMap<(), ()>> = container_port_bindings(&request.ports)?
+        .into_iter()
+        .map(|(key, _)| (key, HashMap::new()))
+        .collect();
+
+    let docker = docker_client.get().await?;
+
+    // Sharing another container's network namespace requires that container to exist
+    if let Some(target) = request.network_mode.as_deref().and_then(|mode| mode.strip_prefix("container:")) {
+        docker
+            .inspect_container(target, None)
+            .await
+            .map_err(|e| format!("Network mode refers to container '{}' which could not be found: {}", target, e))?;
+    }
+
+    let config = bollard::container::Config::<String> {
+        image: Some(request.image.clone()),
+        user: request.user.clone().filter(|user| !user.trim().is_empty()),
+        env: if request.env.is_empty() { None } else { Some(request.env.clone()) },
+        exposed_ports: if exposed_ports.is_empty() { None } else { Some(exposed_ports) },
+        host_config: Some(host_config),
+        ..Default::default()
+    };
+
+    // Fails early on a typo'd image, before an existing container is touched
+    ensure_image(&docker, &request.image, credentials.for_image(&request.image)).await?;
+
+    // Move the existing container aside rather than removing it, so it can be restored if
+    // the new one can't be created
+    let mut previous: Option<(String, String)> = None;
+    if let Some(name) = request.name.as_deref().filter(|_| request.replace_existing) {
+        if let Ok(existing) = docker.inspect_container(name, None).await {
+            let existing_id = existing.id.unwrap_or_else(|| name.to_string());
+            let aside_name = format!("{}-replaced-{}", name, unix_timestamp());
+            docker
+                .rename_container(&existing_id, bollard::container::RenameContainerOptions { name: aside_name })
+                .await
+                .map_err(|e| format!("Failed to move existing container '{}' aside: {}", name, e))?;
+            previous = Some((existing_id, name.to_string()));
+        }
+    }
+
+    let options = request.name.clone().map(|name| bollard::container::CreateContainerOptions {
+        name,
+        ..Default::default()
+    });
+
+    let response = match docker.create_container(options, config).await {
+        Ok(response) => response,
+        Err(e) => {
+            if let Some((existing_id, name)) = &previous {
+                if let Err(rename_error) = docker
+                    .rename_container(existing_id, bollard::container::RenameContainerOptions { name: name.clone() })
+                    .await
+                {
+                    eprintln!("Failed to restore container name '{}': {}", name, rename_error);
+                }
+            }
+            return Err(format!("Failed to create container: {}", e));
+        }
+    };
+
+    let replaced = previous.is_some();
+    if let Some((existing_id, name)) = previous {
+        match docker.stop_container(&existing_id, None).await {
+            Ok(_) => {}
+            // 304 means the container was already stopped
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 304, .. }) => {}
+            Err(e) => return Err(format!("Created {} but failed to stop the container it replaces ('{}'): {}", response.id, name, e)),
+        }
+        docker
+            .remove_container(&existing_id, None)
+            .await
+            .map_err(|e| format!("Created {} but failed to remove the container it replaces ('{}'): {}", response.id, name, e))?;
+    }
+
+    if request.start {
+        docker
+            .start_container(&response.id, None::<bollard::container::StartContainerOptions<String>>)
+            .await
+            .map_err(|e| format!("Container {} was created but failed to start: {}", response.id, e))?;
+    }
+
+    Ok(CreatedContainer {
+        id: response.id,
+        warnings: response.warnings,
+        replaced,
+    })
+}
+
+#[tauri::command]
+async fn start_container(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    docker
+        .start_container(&container_id, None::<bollard::container::StartContainerOptions<String>>)
+        .await
+        .map_err(|e| format!("Failed to start container: {}", e))?;
+
+    Ok(format!("Container {} started successfully", container_id))
+}
+
+fn validate_stop_timeout(timeout: Option<i64>) -> Result<(), String> {
+    match timeout {
+        Some(t) if t < 0 => Err("Stop timeout cannot be negative".to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[tauri::command]
+async fn stop_container(
+    container_id: String,
+    timeout: Option<i64>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    validate_stop_timeout(timeout)?;
+
+    let docker = docker_client.get().await?;
+
+    // Seconds to wait before SIGKILL; None keeps the daemon default (10s unless the container sets its own)
+    let stop_options = timeout.map(|t| bollard::container::StopContainerOptions { t });
+    docker
+        .stop_container(&container_id, stop_options)
+        .await
+        .map_err(|e| format!("Failed to stop container: {}", e))?;
+
+    Ok(format!("Container {} stopped successfully", container_id))
+}
+
+#[tauri::command]
+async fn restart_container(
+    container_id: String,
+    timeout: Option<i64>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    validate_stop_timeout(timeout)?;
+
+    let docker = docker_client.get().await?;
+
+    let restart_options = timeout.map(|t| bollard::container::RestartContainerOptions { t: t as isize });
+    docker
+        .restart_container(&container_id, restart_options)
+        .await
+        .map_err(|e| format!("Failed to restart container: {}", e))?;
+
+    Ok(format!("Container {} restarted successfully", container_id))
+}
+
+// Delay between health polls in `restart_and_wait_healthy`
+const HEALTH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+// Restart and block until the container reports healthy (or, without a healthcheck, running).
+// Returns the final status; errors if the container exits or `timeout_secs` passes first.
+#[tauri::command]
+async fn restart_and_wait_healthy(
+    container_id: String,
+    timeout_secs: u64,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    docker
+        .restart_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to restart container: {}", e))?;
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timeout_secs);
+    loop {
+        let state = docker
+            .inspect_container(&container_id, None)
+            .await
+            .map_err(|e| format!("Failed to inspect container: {}", e))?
+            .state
+            .unwrap_or_default();
+
+        if state.running != Some(true) {
+            let status = state.status.map(|status| status.to_string()).unwrap_or_else(|| "unknown".to_string());
+            return Err(format!("Container {} is {} after restart (exit code {})", container_id, status, state.exit_code.unwrap_or(0)));
+        }
+
+        let status = match state.health.and_then(|health| health.status) {
+            Some(status) => status.to_string(),
+            None => return Ok("running".to_string()),
+        };
+        // Containers without a healthcheck report an empty or "none" health status
+        if status.is_empty() || status == "none" {
+            return Ok("running".to_string());
+        }
+        if status == "healthy" {
+            return Ok(status);
+        }
+
+        if std::time::Instant::now() >= deadline {
+            return Err(format!("Container {} still {} after {}s", container_id, status, timeout_secs));
+        }
+        tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+    }
+}
+
+fn parse_restart_policy(policy: &str) -> Result<bollard::models::RestartPolicyNameEnum, String> {
+    use bollard::models::RestartPolicyNameEnum;
+
+    match policy {
+        "no" => Ok(RestartPolicyNameEnum::NO),
+        "on-failure" => Ok(RestartPolicyNameEnum::ON_FAILURE),
+        "always" => Ok(RestartPolicyNameEnum::ALWAYS),
+        "unless-stopped" => Ok(RestartPolicyNameEnum::UNLESS_STOPPED),
+        _ => Err(format!(
+            "Invalid restart policy '{}'. Expected one of: no, on-failure, always, unless-stopped",
+            policy
+        )),
+    }
+}
+
+#[tauri::command]
+async fn set_restart_policy(
+    container_ids: Vec<String>,
+    policy: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<BulkResult>, String> {
+    let policy_name = parse_restart_policy(&policy)?;
+
+    let docker = docker_client.get().await?;
+
+    let mut results = Vec::with_capacity(container_ids.len());
+
+    for container_id in container_ids {
+        let options = bollard::container::UpdateContainerOptions::<String> {
+            restart_policy: Some(bollard::models::RestartPolicy {
+                name: Some(policy_name),
+                maximum_retry_count: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = match docker.update_container(&container_id, options).await {
+            Ok(_) => BulkResult {
+                id: container_id,
+                success: true,
+                message: format!("Restart policy set to {}", policy),
+            },
+            Err(e) => BulkResult {
+                id: container_id,
+                success: false,
+                message: format!("Failed to update restart policy: {}", e),
+            },
+        };
+
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContainerResources {
+    pub cpu_quota: Option<i64>,
+    pub cpu_period: Option<i64>,
+    pub memory: Option<i64>,
+    pub memory_swap: Option<i64>,
+}
+
+// Changes CPU and memory limits in place; fields left as None keep their current value
+#[tauri::command]
+async fn update_container_resources(
+    container_id: String,
+    cpu_quota: Option<i64>,
+    cpu_period: Option<i64>,
+    memory: Option<i64>,
+    memory_swap: Option<i64>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ContainerResources, String> {
+    if cpu_quota.is_none() && cpu_period.is_none() && memory.is_none() && memory_swap.is_none() {
+        return Err("No resource limits were given to update".to_string());
+    }
+    if let Some(memory) = memory {
+        if memory <= 0 {
+            return Err("Memory limit must be greater than zero".to_string());
+        }
+    }
+    // -1 means unlimited swap
+    if let Some(memory_swap) = memory_swap {
+        if memory_swap == 0 || memory_swap < -1 {
+            return Err("Memory swap limit must be greater than zero, or -1 for unlimited".to_string());
+        }
+    }
+    // -1 means no CPU quota
+    if let Some(cpu_quota) = cpu_quota {
+        if cpu_quota == 0 || cpu_quota < -1 {
+            return Err("CPU quota must be greater than zero, or -1 for no quota".to_string());
+        }
+    }
+    if let Some(cpu_period) = cpu_period {
+        if cpu_period <= 0 {
+            return Err("CPU period must be greater than zero".to_string());
+        }
+    }
+
+    let docker = docker_client.get().await?;
+
+    let options = bollard::container::UpdateContainerOptions::<String> {
+        cpu_quota,
+        cpu_period,
+        memory,
+        memory_swap,
+        ..Default::default()
+    };
+
+    docker
+        .update_container(&container_id, options)
+        .await
+        .map_err(|e| format!("Failed to update container resources: {}", e))?;
+
+    let host_config = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?
+        .host_config
+        .unwrap_or_default();
+
+    Ok(ContainerResources {
+        cpu_quota: host_config.cpu_quota,
+        cpu_period: host_config.cpu_period,
+        memory: host_config.memory,
+        memory_swap: host_config.memory_swap,
+    })
+}
+
+// Anonymous volumes are mounted by a generated name that nothing else references,
+// so recreating without re-binding them silently swaps in a fresh, empty volume
+fn anonymous_volume_binds(inspect: &bollard::models::ContainerInspectResponse) -> Vec<String> {
+    let host_config = inspect.host_config.clone().unwrap_or_default();
+
+    let mut declared: Vec<String> = host_config
+        .binds
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|bind| bind.split(':').nth(1).map(|destination| destination.to_string()))
+        .collect();
+    declared.extend(
+        host_config
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|mount| mount.target),
+    );
+
+    inspect
+        .mounts
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|mount| mount.typ == Some(bollard::models::MountPointTypeEnum::VOLUME))
+        .filter_map(|mount| match (mount.name, mount.destination) {
+            (Some(name), Some(destination)) if !declared.contains(&destination) => {
+                let mode = if mount.rw == Some(false) { ":ro" } else { "" };
+                Some(format!("{}:{}{}", name, destination, mode))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// The `Binds` a recreated container needs: the original ones plus its anonymous volumes
+// re-bound by their generated names, so the new container keeps the same data
+fn recreate_binds(inspect: &bollard::models::ContainerInspectResponse) -> Vec<String> {
+    let mut binds = inspect
+        .host_config
+        .as_ref()
+        .and_then(|host_config| host_config.binds.clone())
+        .unwrap_or_default();
+    binds.extend(anonymous_volume_binds(inspect));
+    binds
+}
+
+// Rebuild the create-time configuration of an existing container from its inspect data
+fn config_from_inspect(inspect: &bollard::models::ContainerInspectResponse) -> bollard::container::Config<String> {
+    let config = inspect.config.clone().unwrap_or_default();
+    let mut host_config = inspect.host_config.clone().unwrap_or_default();
+
+    let binds = recreate_binds(inspect);
+    host_config.binds = if binds.is_empty() { None } else { Some(binds) };
+
+    // Only keep user-supplied endpoint settings; runtime fields are reassigned by the daemon
+    let endpoints_config = inspect
+        .network_settings
+        .as_ref()
+        .and_then(|settings| settings.networks.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(network, endpoint)| {
+            (
+                network,
+                bollard::models::EndpointSettings {
+                    aliases: endpoint.aliases,
+                    ipam_config: endpoint.ipam_config,
+                    links: endpoint.links,
+                    driver_opts: endpoint.driver_opts,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    bollard::container::Config {
+        image: config.image,
+        user: config.user,
+        env: config.env,
+        cmd: config.cmd,
+        entrypoint: config.entrypoint,
+        working_dir: config.working_dir,
+        labels: config.labels,
+        exposed_ports: config.exposed_ports,
+        volumes: config.volumes,
+        tty: config.tty,
+        open_stdin: config.open_stdin,
+        stop_signal: config.stop_signal,
+        stop_timeout: config.stop_timeout,
+        healthcheck: config.healthcheck,
+        host_config: Some(host_config),
+        networking_config: if endpoints_config.is_empty() {
+            None
+        } else {
+            Some(bollard::container::NetworkingConfig { endpoints_config })
+        },
+        ..Default::default()
+    }
+}
+
+// A container's config is the image defaults merged with what the user set. Drop every value
+// that still equals the old image's default so a recreate picks up the new image's defaults
+// instead of pinning the old ones (what Watchtower does on update).
+fn strip_image_defaults(config: &mut bollard::container::Config<String>, image_config: &bollard::models::ContainerConfig) {
+    fn keep_if_changed<T: PartialEq>(value: &mut Option<T>, default: &Option<T>) {
+        if value.is_some() && value == default {
+            *value = None;
+        }
+    }
+
+    if let (Some(env), Some(image_env)) = (config.env.as_mut(), image_config.env.as_ref()) {
+        env.retain(|entry| !image_env.contains(entry));
+    }
+    if let (Some(labels), Some(image_labels)) = (config.labels.as_mut(), image_config.labels.as_ref()) {
+        labels.retain(|key, value| image_labels.get(key) != Some(value));
+    }
+    if let (Some(ports), Some(image_ports)) = (config.exposed_ports.as_mut(), image_config.exposed_ports.as_ref()) {
+        ports.retain(|port, _| !image_ports.contains_key(port));
+    }
+    if let (Some(volumes), Some(image_volumes)) = (config.volumes.as_mut(), image_config.volumes.as_ref()) {
+        volumes.retain(|volume, _| !image_volumes.contains_key(volume));
+    }
+
+    // A user-set entrypoint resets the image CMD, so the cmd is only a default when the
+    // entrypoint is too
+    let entrypoint_is_default = config.entrypoint.is_none() || config.entrypoint == image_config.entrypoint;
+    keep_if_changed(&mut config.entrypoint, &image_config.entrypoint);
+    if entrypoint_is_default {
+        keep_if_changed(&mut config.cmd, &image_config.cmd);
+    }
+
+    keep_if_changed(&mut config.user, &image_config.user);
+    keep_if_changed(&mut config.working_dir, &image_config.working_dir);
+    keep_if_changed(&mut config.stop_signal, &image_config.stop_signal);
+    keep_if_changed(&mut config.healthcheck, &image_config.healthcheck);
+}
+
+// Replace a container with a new one built from the same configuration, after letting
+// `customize` adjust it. Anonymous volumes are carried over so their data survives.
+async fn recreate_with<F>(docker: &Docker, container_id: &str, customize: F) -> Result<String, String>
+where
+    F: FnOnce(&mut bollard::container::Config<String>),
+{
+    let inspect = docker
+        .inspect_container(container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+
+    let name = inspect
+        .name
+        .clone()
+        .unwrap_or_default()
+        .trim_start_matches('/')
+        .to_string();
+    let was_running = inspect
+        .state
+        .as_ref()
+        .and_then(|state| state.running)
+        .unwrap_or(false);
+
+    let mut config = config_from_inspect(&inspect);
+    customize(&mut config);
+
+    // Stop first so the ports are free, then move the original aside under a temporary name
+    // so it can be restored if the replacement can't be created or started
+    if was_running {
+        docker
+            .stop_container(container_id, None)
+            .await
+            .map_err(|e| format!("Failed to stop container: {}", e))?;
+    }
+    if !name.is_empty() {
+        if let Err(e) = docker
+            .rename_container(container_id, bollard::container::RenameContainerOptions {
+                name: format!("{}-replaced-{}", name, unix_timestamp()),
+            })
+            .await
+        {
+            restore_original(docker, container_id, None, was_running).await;
+            return Err(format!("Failed to move container '{}' aside: {}", name, e));
+        }
+    }
+    let original_name = Some(name.as_str()).filter(|name| !name.is_empty());
+
+    let options = original_name.map(|name| bollard::container::CreateContainerOptions {
+        name: name.to_string(),
+        ..Default::default()
+    });
+
+    let response = match docker.create_container(options, config).await {
+        Ok(response) => response,
+        Err(e) => {
+            restore_original(docker, container_id, original_name, was_running).await;
+            return Err(format!("Failed to recreate container '{}', the original was restored: {}", name, e));
+        }
+    };
+
+    if was_running {
+        if let Err(e) = docker
+            .start_container(&response.id, None::<bollard::container::StartContainerOptions<String>>)
+            .await
+        {
+            let _ = docker
+                .remove_container(&response.id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+                .await;
+            restore_original(docker, container_id, original_name, was_running).await;
+            return Err(format!("Failed to start recreated container '{}', the original was restored: {}", name, e));
+        }
+    }
+
+    // Never pass `v: true` here, the anonymous volumes are now attached to the new container
+    docker
+        .remove_container(container_id, Some(RemoveContainerOptions { v: false, ..Default::default() }))
+        .await
+        .map_err(|e| format!("Recreated container as {} but failed to remove the original: {}", response.id, e))?;
+
+    Ok(response.id)
+}
+
+// Undo a failed recreate: give the original container its name back and restart it
+async fn restore_original(docker: &Docker, container_id: &str, name: Option<&str>, was_running: bool) {
+    if let Some(name) = name {
+        if let Err(e) = docker
+            .rename_container(container_id, bollard::container::RenameContainerOptions { name: name.to_string() })
+            .await
+        {
+            eprintln!("Failed to restore container name '{}': {}", name, e);
+        }
+    }
+    if was_running {
+        if let Err(e) = docker
+            .start_container(container_id, None::<bollard::container::StartContainerOptions<String>>)
+            .await
+        {
+            eprintln!("Failed to restart original container {}: {}", container_id, e);
+        }
+    }
+}
+
+#[tauri::command]
+async fn recreate_container(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    recreate_with(&docker, &container_id, |_| {}).await
+}
+
+// How long a registry digest lookup is trusted before asking the registry again
+const IMAGE_UPDATE_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+// Failed lookups are retried sooner, but not on every check
+const IMAGE_UPDATE_FAILURE_TTL: std::time::Duration = std::time::Duration::from_secs(2 * 60);
+
+// Pause for a registry that answered 429. The daemon doesn't pass Retry-After through the
+// distribution endpoint, so this matches Docker Hub's rate limit window granularity.
+const REGISTRY_RATE_LIMIT_BACKOFF: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone)]
+enum RegistryLookupError {
+    RateLimited(String),
+    Failed(String),
+}
+
+impl RegistryLookupError {
+    fn message(&self) -> String {
+        match self {
+            RegistryLookupError::RateLimited(registry) => format!("Rate limited by {}; will retry later", registry),
+            RegistryLookupError::Failed(message) => message.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ImageUpdateEntries {
+    // Image -> when the result expires, and the digest or why it couldn't be fetched
+    digests: HashMap<String, (std::time::Instant, Result<String, RegistryLookupError>)>,
+    // Registry -> end of its rate limit backoff
+    backoff: HashMap<String, std::time::Instant>,
+}
+
+#[derive(Default)]
+pub struct ImageUpdateCache(std::sync::Mutex<ImageUpdateEntries>);
+
+// Split "repo:tag" into its repository and tag, leaving registry ports intact
+fn split_image_tag(reference: &str) -> (&str, &str) {
+    let reference = reference.split('@').next().unwrap_or(reference);
+    match reference.rfind(':') {
+        Some(pos) if !reference[pos..].contains('/') => (&reference[..pos], &reference[pos + 1..]),
+        _ => (reference, "latest"),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageReference {
+    pub registry: String,
+    pub repository: String,
+    pub tag: String,
+    pub digest: Option<String>,
+}
+
+static IMAGE_TAG_PATTERN: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+static IMAGE_COMPONENT_PATTERN: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+
+// Parse an image reference the way the Docker CLI does: the first path component is a
+// registry only if it looks like a host (has a '.' or ':' or is "localhost"), Docker Hub
+// official images live under "library/", and the tag defaults to "latest"
+fn parse_image_reference(input: &str) -> Result<ImageReference, String> {
+    let reference = input.trim();
+    if reference.is_empty() {
+        return Err("Image reference is empty".to_string());
+    }
+
+    let (name_and_tag, digest) = match reference.split_once('@') {
+        Some((name, digest)) => {
+            let valid = digest
+                .split_once(':')
+                .map(|(algorithm, hex)| !algorithm.is_empty() && !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                .unwrap_or(false);
+            if !valid {
+                return Err(format!("Invalid digest '{}' in '{}'", digest, input));
+            }
+            (name, Some(digest.to_string()))
+        }
+        None => (reference, None),
+    };
+
+    let (name, tag) = split_image_tag(name_and_tag);
+    let tag_pattern = IMAGE_TAG_PATTERN.get_or_init(|| Regex::new(r"^[A-Za-z0-9_][A-Za-z0-9_.-]{0,127}$").unwrap());
+    if !tag_pattern.is_match(tag) {
+        return Err(format!("Invalid tag '{}' in '{}'", tag, input));
+    }
+
+    let (registry, path) = match name.split_once('/') {
+        Some((first, rest)) if first.contains('.') || first.contains(':') || first == "localhost" => {
+            (first.to_string(), rest.to_string())
+        }
+        _ => ("docker.io".to_string(), name.to_string()),
+    };
+    let registry = if registry == "index.docker.io" { "docker.io".to_string() } else { registry };
+
+    let component_pattern =
+        IMAGE_COMPONENT_PATTERN.get_or_init(|| Regex::new(r"^[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*$").unwrap());
+    if path.is_empty() || !path.split('/').all(|component| component_pattern.is_match(component)) {
+        return Err(format!("Invalid repository name '{}' in '{}'", path, input));
+    }
+
+    let repository = if registry == "docker.io" && !path.contains('/') {
+        format!("library/{}", path)
+    } else {
+        path
+    };
+
+    Ok(ImageReference {
+        registry,
+        repository,
+        tag: tag.to_string(),
+        digest,
+    })
+}
+
+#[tauri::command]
+async fn normalize_image_reference(input: String) -> Result<ImageReference, String> {
+    parse_image_reference(&input)
+}
+
+async fn local_image_digest(docker: &Docker, image: &str) -> Option<String> {
+    let inspect = docker.inspect_image(image).await.ok()?;
+    let (repository, _) = split_image_tag(image);
+    let repo_digests = inspect.repo_digests.unwrap_or_default();
+
+    repo_digests
+        .iter()
+        .find(|digest| digest.split('@').next() == Some(repository))
+        .or_else(|| repo_digests.first())
+        .and_then(|digest| digest.split('@').nth(1))
+        .map(|digest| digest.to_string())
+}
+
+async fn registry_image_digest(
+    docker: &Docker,
+    cache: &ImageUpdateCache,
+    image: &str,
+) -> Result<String, RegistryLookupError> {
+    let registry = image_registry(image);
+    let now = std::time::Instant::now();
+
+    if let Ok(entries) = cache.0.lock() {
+        if let Some((expires_at, result)) = entries.digests.get(image) {
+            if now < *expires_at {
+                return result.clone();
+            }
+        }
+        // Don't add to the load of a registry that is already throttling us
+        if entries.backoff.get(&registry).is_some_and(|until| now < *until) {
+            return Err(RegistryLookupError::RateLimited(registry));
+        }
+    }
+
+    // Resolved by the daemon with a manifest HEAD request against the registry
+    let result = match docker.inspect_registry_image(image, None).await {
+        Ok(distribution) => distribution
+            .descriptor
+            .digest
+            .ok_or_else(|| RegistryLookupError::Failed(format!("Registry returned no digest for {}", image))),
+        Err(bollard::errors::Error::DockerResponseServerError { status_code, message })
+            if status_code == 429 || message.contains("toomanyrequests") =>
+        {
+            Err(RegistryLookupError::RateLimited(registry.clone()))
+        }
+        Err(e) => Err(RegistryLookupError::Failed(format!("Failed to query registry for {}: {}", image, e))),
+    };
+
+    if let Ok(mut entries) = cache.0.lock() {
+        let ttl = match &result {
+            Ok(_) => IMAGE_UPDATE_CACHE_TTL,
+            Err(RegistryLookupError::RateLimited(_)) => {
+                entries.backoff.insert(registry, now + REGISTRY_RATE_LIMIT_BACKOFF);
+                REGISTRY_RATE_LIMIT_BACKOFF
+            }
+            Err(RegistryLookupError::Failed(_)) => IMAGE_UPDATE_FAILURE_TTL,
+        };
+        entries.digests.insert(image.to_string(), (now + ttl, result.clone()));
+    }
+
+    result
+}
+
+#[tauri::command]
+async fn check_image_updates(
+    cache: tauri::State<'_, ImageUpdateCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ImageUpdateStatus>, String> {
+    let docker = docker_client.get().await?;
+
+    let running = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            filters: HashMap::from([("status".to_string(), vec!["running".to_string()])]),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    // Look each image up once, even when several containers share it
+    let mut digests: HashMap<String, (Option<String>, Result<String, RegistryLookupError>)> = HashMap::new();
+    let mut results = Vec::new();
+
+    for container in running.into_iter().map(container_info_from_summary) {
+        // Containers created from an image id or digest have no tag to track
+        if container.image.starts_with("sha256:") || container.image.contains('@') {
+            continue;
+        }
+
+        if !digests.contains_key(&container.image) {
+            let current = local_image_digest(&docker, &container.image).await;
+            let latest = registry_image_digest(&docker, &cache, &container.image).await;
+            digests.insert(container.image.clone(), (current, latest));
+        }
+
+        let (current_digest, latest) = digests[&container.image].clone();
+        let latest_digest = latest.as_ref().ok().cloned();
+        let update_available = matches!((&current_digest, &latest_digest), (Some(current), Some(latest)) if current != latest);
+
+        results.push(ImageUpdateStatus {
+            container_id: container.id,
+            name: container.name,
+            image: container.image,
+            current_digest,
+            latest_digest,
+            update_available,
+            rate_limited: matches!(latest, Err(RegistryLookupError::RateLimited(_))),
+            error: latest.err().map(|e| e.message()),
+        });
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+async fn list_images(docker_client: tauri::State<'_, DockerClient>) -> Result<Vec<ImageInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let options = Some(ListImagesOptions::<String> {
+        all: true,
+        ..Default::default()
+    });
+
+    let images = docker
+        .list_images(options)
+        .await
+        .map_err(|e| format!("Failed to list images: {}", e))?;
+
+    let image_info: Vec<ImageInfo> = images.into_iter().map(image_info_from_summary).collect();
+
+    Ok(image_info)
+}
+
+fn image_info_from_summary(image: bollard::models::ImageSummary) -> ImageInfo {
+    ImageInfo {
+        id: image.id,
+        repo_tags: image.repo_tags,
+        repo_digests: image.repo_digests,
+        created: image.created,
+        size: image.size,
+        virtual_size: image.virtual_size.unwrap_or(0),
+        shared_size: image.shared_size,
+        labels: image.labels,
+    }
+}
+
+// Local images built on top of `image_id`: their layer stack starts with all of its layers
+#[tauri::command]
+async fn images_based_on(
+    image_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ImageInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let base = docker
+        .inspect_image(&image_id)
+        .await
+        .map_err(|e| format!("Failed to inspect image: {}", e))?;
+    let base_id = base.id.unwrap_or_default();
+    let base_layers = base.root_fs.and_then(|root_fs| root_fs.layers).unwrap_or_default();
+    if base_layers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let images = docker
+        .list_images(Some(ListImagesOptions::<String> {
+            all: false,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list images: {}", e))?;
+
+    // The listing has no layer data, so every candidate needs an inspect
+    let inspected = futures_util::future::join_all(images.iter().map(|image| docker.inspect_image(&image.id))).await;
+
+    Ok(images
+        .into_iter()
+        .zip(inspected)
+        .filter(|(image, _)| image.id != base_id)
+        .filter(|(_, inspect_result)| {
+            inspect_result
+                .as_ref()
+                .ok()
+                .and_then(|inspect| inspect.root_fs.as_ref())
+                .and_then(|root_fs| root_fs.layers.as_ref())
+                .is_some_and(|layers| layers.len() > base_layers.len() && layers.starts_with(&base_layers))
+        })
+        .map(|(image, _)| image_info_from_summary(image))
+        .collect())
+}
+
+// Image ids referenced by any container, running or not
+async fn images_in_use(docker: &Docker) -> Result<std::collections::HashSet<String>, String> {
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    Ok(containers.into_iter().filter_map(|container| container.image_id).collect())
+}
+
+#[tauri::command]
+async fn image_age_report(docker_client: tauri::State<'_, DockerClient>) -> Result<Vec<ImageAge>, String> {
+    let docker = docker_client.get().await?;
+
+    let images = docker
+        .list_images(Some(ListImagesOptions::<String> {
+            all: false,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list images: {}", e))?;
+
+    let in_use = images_in_use(&docker).await?;
+    let now = unix_timestamp();
+
+    let mut report: Vec<ImageAge> = images
+        .into_iter()
+        .map(|image| ImageAge {
+            in_use: in_use.contains(&image.id),
+            age_days: ((now - image.created).max(0) / 86_400) as u64,
+            id: image.id,
+            repo_tags: image.repo_tags,
+            created: image.created,
+        })
+        .collect();
+
+    report.sort_by(|a, b| a.created.cmp(&b.created));
+
+    Ok(report)
+}
+
+#[tauri::command]
+async fn remove_image(
+    image_id: String,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    // Use RemoveImageOptions to properly handle image removal
+    let options = Some(bollard::image::RemoveImageOptions {
+        force: false,
+        noprune: false,
+    });
+
+    let result = docker
+        .remove_image(&image_id, options, None)
+        .await
+        .map_err(|e| {
+            let error_msg = format!("Failed to remove image: {}", e);
+            // Check if it's a dependency error and suggest force removal
+            if error_msg.contains("conflict") || error_msg.contains("being used") {
+                format!("{}\n\nTip: The image might be in use by a container. Stop and remove dependent containers first, or use force removal.", error_msg)
+            } else {
+                error_msg
+            }
+        })?;
+
+    // The result is a vector of removal results, one per untagged reference or deleted layer
+    let event_name = format!("image-remove-{}", event_safe(&image_id));
+    for item in &result {
+        let _ = app_handle.emit(
+            &event_name,
+            ImageRemoveEvent {
+                untagged: item.untagged.clone(),
+                deleted: item.deleted.clone(),
+            },
+        );
+    }
+
+    let removed_count = result.len();
+    
+    if removed_count > 0 {
+        Ok(format!("Image {} removed successfully ({} layers removed)", image_id, removed_count))
+    } else {
+        Ok(format!("Image {} removed successfully", image_id))
+    }
+}
+
+#[tauri::command]
+async fn force_remove_image(image_id: String, docker_client: tauri::State<'_, DockerClient>) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    // Use force removal for stubborn images
+    let options = Some(bollard::image::RemoveImageOptions {
+        force: true,
+        noprune: false,
+    });
+
+    let result = docker
+        .remove_image(&image_id, options, None)
+        .await
+        .map_err(|e| format!("Failed to force remove image: {}", e))?;
+
+    let removed_count = result.len();
+    
+    if removed_count > 0 {
+        Ok(format!("Image {} force removed successfully ({} layers removed)", image_id, removed_count))
+    } else {
+        Ok(format!("Image {} force removed successfully", image_id))
+    }
+}
+
+// Tracks per-layer download progress so a pull can be reported as one overall percentage
+#[derive(Default)]
+struct PullProgressTracker {
+    layers: HashMap<String, (u64, u64)>,
+}
+
+impl PullProgressTracker {
+    fn update(&mut self, layer_id: &str, status: &str, current: Option<i64>, total: Option<i64>) {
+        match status {
+            "Downloading" => {
+                // Layers that don't report a total size are left out until they do
+                if let Some(total) = total.filter(|t| *t > 0) {
+                    let current = current.unwrap_or(0).clamp(0, total);
+                    self.layers.insert(layer_id.to_string(), (current as u64, total as u64));
+                }
+            }
+            "Download complete" | "Pull complete" | "Already exists" => {
+                if let Some(entry) = self.layers.get_mut(layer_id) {
+                    entry.0 = entry.1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn summary(&self, image: &str) -> ImagePullProgress {
+        let (downloaded_bytes, total_bytes) = self
+            .layers
+            .values()
+            .fold((0u64, 0u64), |(downloaded, total), (current, layer_total)| {
+                (downloaded + current, total + layer_total)
+            });
+
+        ImagePullProgress {
+            image: image.to_string(),
+            downloaded_bytes,
+            total_bytes,
+            percentage: if total_bytes > 0 { downloaded_bytes as f64 / total_bytes as f64 * 100.0 } else { 0.0 },
+        }
+    }
+}
+
+// Docker Hub credentials are keyed by the legacy index address, like ~/.docker/config.json
+const DOCKER_HUB_AUTH_ADDRESS: &str = "https://index.docker.io/v1/";
+
+// Credentials entered by the user, keyed by registry host ("docker.io" for Docker Hub)
+#[derive(Default)]
+pub struct RegistryCredentials(std::sync::Mutex<HashMap<String, RegistryAuth>>);
+
+impl RegistryCredentials {
+    fn store(&self, registry: &str, auth: RegistryAuth) {
+        if let Ok(mut credentials) = self.0.lock() {
+            credentials.insert(registry.to_string(), auth);
+        }
+    }
+
+    // Every stored login, keyed by server address, for builds that pull several base images
+    fn all(&self) -> HashMap<String, bollard::auth::DockerCredentials> {
+        let Ok(credentials) = self.0.lock() else { return HashMap::new() };
+        credentials
+            .iter()
+            .map(|(registry, auth)| {
+                let credentials = docker_credentials(registry, auth.clone());
+                (credentials.serveraddress.clone().unwrap_or_default(), credentials)
+            })
+            .collect()
+    }
+
+    // Credentials for the registry `image` lives on, if the user has logged in to it
+    fn for_image(&self, image: &str) -> Option<bollard::auth::DockerCredentials> {
+        let registry = image_registry(image);
+        let auth = self.0.lock().ok()?.get(&registry).cloned()?;
+        Some(docker_credentials(&registry, auth))
+    }
+}
+
+fn image_registry(image: &str) -> String {
+    parse_image_reference(image)
+        .map(|reference| reference.registry)
+        .unwrap_or_else(|_| "docker.io".to_string())
+}
+
+fn normalize_registry(registry: &str) -> String {
+    let registry = registry.trim().trim_start_matches("https://").trim_start_matches("http://");
+    let registry = registry.split('/').next().unwrap_or(registry);
+    match registry {
+        "" | "index.docker.io" | "registry-1.docker.io" => "docker.io".to_string(),
+        _ => registry.to_string(),
+    }
+}
+
+fn docker_credentials(registry: &str, auth: RegistryAuth) -> bollard::auth::DockerCredentials {
+    let default_address = if registry == "docker.io" { DOCKER_HUB_AUTH_ADDRESS.to_string() } else { registry.to_string() };
+
+    bollard::auth::DockerCredentials {
+        username: Some(auth.username),
+        password: Some(auth.password),
+        serveraddress: Some(auth.server_address.filter(|address| !address.trim().is_empty()).unwrap_or(default_address)),
+        identitytoken: auth.identity_token.filter(|token| !token.is_empty()),
+        ..Default::default()
+    }
+}
+
+// Stores credentials for `registry` once they've been checked by resolving `probe_image` there.
+// Docker Hub falls back to hello-world; other registries have no image everyone can read, so
+// they need a probe image.
+#[tauri::command]
+async fn docker_login(
+    registry: String,
+    username: String,
+    password: String,
+    probe_image: Option<String>,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    if username.trim().is_empty() || password.is_empty() {
+        return Err("A username and password are required to log in".to_string());
+    }
+
+    let registry = normalize_registry(&registry);
+    let auth = RegistryAuth {
+        username: username.trim().to_string(),
+        password,
+        server_address: None,
+        identity_token: None,
+    };
+
+    let probe_image = probe_image
+        .filter(|image| !image.trim().is_empty())
+        .or_else(|| (registry == "docker.io").then(|| "hello-world".to_string()));
+
+    // Without an image to resolve there's nothing to check the credentials against
+    let Some(image) = probe_image else {
+        return Err(format!(
+            "Can't verify credentials for {} without a probe image; give an image on that registry the account can read",
+            registry
+        ));
+    };
+    let image = if registry == "docker.io" || image.starts_with(&format!("{}/", registry)) {
+        image
+    } else {
+        format!("{}/{}", registry, image)
+    };
+
+    let docker = docker_client.get().await?;
+    docker
+        .inspect_registry_image(&image, Some(docker_credentials(&registry, auth.clone())))
+        .await
+        // Never echo the daemon's error, the request it describes carries the password
+        .map_err(|e| match e {
+            bollard::errors::Error::DockerResponseServerError { status_code, .. } => match status_code {
+                401 => format!("Login to {} failed: invalid username or password", registry),
+                403 => format!("Login to {} failed: the account has no access to {}", registry, image),
+                404 => format!("Login to {} could not be verified: probe image {} was not found", registry, image),
+                429 => format!("Login to {} could not be verified: the registry is rate limiting requests", registry),
+                status => format!("Login to {} failed: the registry returned status {}", registry, status),
+            },
+            _ => format!("Login to {} failed: could not reach the registry", registry),
+        })?;
+
+    credentials.store(&registry, auth);
+
+    Ok(format!("Logged in to {} as {}", registry, username.trim()))
+}
+
+#[tauri::command]
+async fn pull_image(
+    image: String,
+    tag: Option<String>,
+    auth: Option<RegistryAuth>,
+    app_handle: tauri::AppHandle,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    // Remember credentials given with a pull so later pulls from the registry reuse them
+    if let Some(auth) = auth {
+        credentials.store(&image_registry(&image), auth);
+    }
+
+    let docker = docker_client.get().await?;
+
+    let tag = tag.filter(|t| !t.trim().is_empty()).unwrap_or_else(|| "latest".to_string());
+    pull_with_progress(&docker, &image, &tag, &app_handle).await?;
+
+    Ok(format!("Image {}:{} pulled successfully", image, tag))
+}
+
+// Pull `image:tag`, reporting per-layer and overall progress on `image-pull-*` events and
+// finishing with either `image-pull-complete-*` or `image-pull-error-*`
+async fn pull_with_progress(docker: &Docker, image: &str, tag: &str, app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let event_image = event_safe(image);
+
+    let result = stream_pull(docker, image, tag, app_handle, &event_image).await;
+    match &result {
+        Ok(()) => {
+            let _ = app_handle.emit(
+                &format!("image-pull-complete-{}", event_image),
+                format!("Image {}:{} pulled successfully", image, tag),
+            );
+        }
+        Err(e) => {
+            let _ = app_handle.emit(&format!("image-pull-error-{}", event_image), e.clone());
+        }
+    }
+
+    result
+}
+
+async fn stream_pull(
+    docker: &Docker,
+    image: &str,
+    tag: &str,
+    app_handle: &tauri::AppHandle,
+    event_image: &str,
+) -> Result<(), String> {
+    let options = Some(bollard::image::CreateImageOptions {
+        from_image: image.to_string(),
+        tag: tag.to_string(),
+        ..Default::default()
+    });
+
+    let credentials = app_handle.state::<RegistryCredentials>().for_image(image);
+
+    let mut tracker = PullProgressTracker::default();
+    let mut pull_stream = docker.create_image(options, None, credentials);
+
+    while let Some(result) = pull_stream.next().await {
+        let info = result.map_err(|e| format!("Failed to pull image {}:{}: {}", image, tag, e))?;
+        // Registry failures can arrive as an error message inside an otherwise successful stream
+        if let Some(error) = info.error {
+            return Err(format!("Failed to pull image {}:{}: {}", image, tag, error));
+        }
+
+        let (current, total) = info
+            .progress_detail
+            .as_ref()
+            .map(|detail| (detail.current, detail.total))
+            .unwrap_or((None, None));
+
+        if let (Some(id), Some(status)) = (&info.id, &info.status) {
+            tracker.update(id, status, current, total);
+        }
+
+        let _ = app_handle.emit(
+            &format!("image-pull-{}", event_image),
+            ImagePullEvent {
+                id: info.id,
+                status: info.status,
+                current,
+                total,
+            },
+        );
+        let _ = app_handle.emit(&format!("image-pull-progress-{}", event_image), tracker.summary(image));
+    }
+
+    Ok(())
+}
+
+// Push `image:tag`, reporting per-layer status on `image-push-*` events and finishing
+// with either `image-push-complete-*` or `image-push-error-*`
+#[tauri::command]
+async fn push_image(
+    image: String,
+    tag: Option<String>,
+    auth: Option<RegistryAuth>,
+    app_handle: tauri::AppHandle,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    if let Some(auth) = auth {
+        credentials.store(&image_registry(&image), auth);
+    }
+
+    let docker = docker_client.get().await?;
+
+    let tag = tag.filter(|t| !t.trim().is_empty()).unwrap_or_else(|| "latest".to_string());
+    let event_image = event_safe(&image);
+
+    let options = Some(bollard::image::PushImageOptions { tag: tag.clone() });
+    let mut push_stream = docker.push_image(&image, options, credentials.for_image(&image));
+
+    let mut result = Ok(());
+    while let Some(item) = push_stream.next().await {
+        let info = match item {
+            Ok(info) => info,
+            Err(e) => {
+                result = Err(format!("Failed to push image {}:{}: {}", image, tag, e));
+                break;
+            }
+        };
+        if let Some(error) = info.error {
+            result = Err(format!("Failed to push image {}:{}: {}", image, tag, error));
+            break;
+        }
+
+        let _ = app_handle.emit(
+            &format!("image-push-{}", event_image),
+            ImagePushEvent {
+                status: info.status,
+                progress: info.progress,
+            },
+        );
+    }
+
+    match result {
+        Ok(()) => {
+            let message = format!("Image {}:{} pushed successfully", image, tag);
+            let _ = app_handle.emit(&format!("image-push-complete-{}", event_image), message.clone());
+            Ok(message)
+        }
+        Err(e) => {
+            let _ = app_handle.emit(&format!("image-push-error-{}", event_image), e.clone());
+            Err(e)
+        }
+    }
+}
+
+// Watchtower-style update: pull the container's tag and recreate it only if the pull
+// produced a different image
+#[tauri::command]
+async fn pull_and_recreate(
+    container_id: String,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    let inspect = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+
+    let image = inspect.config.as_ref().and_then(|config| config.image.clone()).unwrap_or_default();
+    if image.is_empty() || image.starts_with("sha256:") || image.contains('@') {
+        return Err(format!("Container {} is pinned to an image id or digest and has no tag to update", container_id));
+    }
+    let current_image_id = inspect.image.unwrap_or_default();
+
+    // Read the old image's defaults before the pull can untag it
+    let old_image_config = docker
+        .inspect_image(&current_image_id)
+        .await
+        .map_err(|e| format!("Failed to inspect current image {}: {}", current_image_id, e))?
+        .config
+        .unwrap_or_default();
+
+    let (repository, tag) = split_image_tag(&image);
+    pull_with_progress(&docker, repository, tag, &app_handle).await?;
+
+    let pulled_image_id = docker
+        .inspect_image(&image)
+        .await
+        .map_err(|e| format!("Failed to inspect image {}: {}", image, e))?
+        .id
+        .unwrap_or_default();
+
+    if pulled_image_id == current_image_id {
+        return Ok(format!("Container {} is already running the latest {}", container_id, image));
+    }
+
+    // The recreated container resolves the same tag, which now points at the pulled image, and
+    // keeps only the settings the user made on top of the old image
+    recreate_with(&docker, &container_id, |config| strip_image_defaults(config, &old_image_config)).await?;
+
+    Ok(format!("Container {} updated to the latest {}", container_id, image))
+}
+
+#[tauri::command]
+async fn import_image_from_url(
+    url: String,
+    repo: String,
+    tag: String,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let scheme = url.split_once("://").map(|(scheme, _)| scheme.to_lowercase());
+    if !matches!(scheme.as_deref(), Some("http") | Some("https")) {
+        return Err(format!("Only http(s) URLs can be imported, got '{}'", url));
+    }
+    if repo.trim().is_empty() {
+        return Err("A repository name is required for the imported image".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    let tag = if tag.trim().is_empty() { "latest".to_string() } else { tag };
+    let event_name = format!("image-import-{}", event_safe(&repo));
+
+    // The daemon downloads the rootfs tarball itself (`docker import <url>`)
+    let options = Some(bollard::image::CreateImageOptions {
+        from_src: url.clone(),
+        repo: repo.clone(),
+        tag: tag.clone(),
+        ..Default::default()
+    });
+
+    let mut import_stream = docker.create_image(options, None, None);
+    while let Some(result) = import_stream.next().await {
+        let info = result.map_err(|e| format!("Failed to import image from {}: {}", url, e))?;
+        if let Some(error) = info.error {
+            return Err(format!("Failed to import image from {}: {}", url, error));
+        }
+
+        let (current, total) = info
+            .progress_detail
+            .map(|detail| (detail.current, detail.total))
+            .unwrap_or((None, None));
+        let _ = app_handle.emit(
+            &event_name,
+            ImagePullEvent {
+                id: info.id,
+                status: info.status,
+                current,
+                total,
+            },
+        );
+    }
+
+    Ok(format!("Imported {} as {}:{}", url, repo, tag))
+}
+
+#[tauri::command]
+async fn save_image(
+    image_id: String,
+    output_path: String,
+    chunk_size: Option<usize>,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    use tokio::io::AsyncWriteExt;
+
+    let docker = docker_client.get().await?;
+
+    let chunk_size = chunk_size.filter(|size| *size > 0).unwrap_or(DEFAULT_SAVE_CHUNK_SIZE);
+    let event_name = format!("image-save-progress-{}", event_safe(&image_id));
+
+    let started = std::time::Instant::now();
+    let progress = |bytes_written: u64, done: bool, error: Option<String>| {
+        let elapsed = started.elapsed().as_secs_f64();
+        ImageSaveProgress {
+            image: image_id.clone(),
+            bytes_written,
+            throughput_mbps: if elapsed > 0.0 { bytes_written as f64 / 1_048_576.0 / elapsed } else { 0.0 },
+            done,
+            error,
+        }
+    };
+
+    let mut file = match tokio::fs::File::create(&output_path).await {
+        Ok(file) => file,
+        Err(e) => {
+            let message = format!("Failed to create output file '{}': {}", output_path, e);
+            let _ = app_handle.emit(&event_name, progress(0, true, Some(message.clone())));
+            return Err(message);
+        }
+    };
+
+    let mut bytes_written: u64 = 0;
+    let result: Result<(), String> = async {
+        let mut buffer: Vec<u8> = Vec::with_capacity(chunk_size);
+        let mut export_stream = docker.export_image(&image_id);
+
+        while let Some(chunk) = export_stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Failed to export image: {}", e))?;
+            buffer.extend_from_slice(&chunk);
+
+            // Only hit the disk once a full chunk has accumulated
+            while buffer.len() >= chunk_size {
+                let rest = buffer.split_off(chunk_size);
+                file.write_all(&buffer)
+                    .await
+                    .map_err(|e| format!("Failed to write image archive: {}", e))?;
+                bytes_written += buffer.len() as u64;
+                buffer = rest;
+
+                let _ = app_handle.emit(&event_name, progress(bytes_written, false, None));
+
+                // Give the runtime a chance to service UI commands between chunks
+                tokio::task::yield_now().await;
+            }
+        }
+
+        if !buffer.is_empty() {
+            file.write_all(&buffer)
+                .await
+                .map_err(|e| format!("Failed to write image archive: {}", e))?;
+            bytes_written += buffer.len() as u64;
+        }
+
+        file.flush()
+            .await
+            .map_err(|e| format!("Failed to flush image archive: {}", e))
+    }
+    .await;
+
+    if let Err(e) = result {
+        // A truncated archive would only fail later, on load
+        drop(file);
+        let _ = tokio::fs::remove_file(&output_path).await;
+        let _ = app_handle.emit(&event_name, progress(bytes_written, true, Some(e.clone())));
+        return Err(e);
+    }
+
+    let _ = app_handle.emit(&event_name, progress(bytes_written, true, None));
+
+    Ok(format!("Image {} saved to {} ({} bytes)", image_id, output_path, bytes_written))
+}
+
+// Entry of the manifest.json found in `docker save` archives
+#[derive(Debug, Deserialize)]
+struct DockerArchiveManifest {
+    #[serde(rename = "Config")]
+    config: String,
+    #[serde(rename = "RepoTags", default)]
+    repo_tags: Option<Vec<String>>,
+    #[serde(rename = "Layers")]
+    layers: Vec<String>,
+}
+
+// Append data to an OCI layout as a content-addressed blob, returning its digest and size
+fn append_oci_blob<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    data: &[u8],
+    written: &mut std::collections::HashSet<String>,
+) -> Result<(String, u64), String> {
+    use sha2::Digest;
+
+    let hex = format!("{:x}", sha2::Sha256::digest(data));
+    if written.insert(hex.clone()) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, format!("blobs/sha256/{}", hex), data)
+            .map_err(|e| format!("Failed to write OCI blob: {}", e))?;
+    }
+
+    Ok((format!("sha256:{}", hex), data.len() as u64))
+}
+
+// Same as `append_oci_blob` but streams a (potentially multi-GB) layer file from disk
+fn append_oci_blob_file<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    path: &std::path::Path,
+    written: &mut std::collections::HashSet<String>,
+) -> Result<(String, u64), String> {
+    use sha2::Digest;
+
+    let mut hasher = sha2::Sha256::new();
+    let mut file = std::fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let size = std::io::copy(&mut file, &mut hasher).map_err(|e| format!("Failed to hash {}: {}", path.display(), e))?;
+    let hex = format!("{:x}", hasher.finalize());
+
+    if written.insert(hex.clone()) {
+        builder
+            .append_path_with_name(path, format!("blobs/sha256/{}", hex))
+            .map_err(|e| format!("Failed to write OCI blob: {}", e))?;
+    }
+
+    Ok((format!("sha256:{}", hex), size))
+}
+
+// Repackage a Docker-format image archive as an OCI image layout archive
+fn docker_archive_to_oci(archive: &std::path::Path, output: &std::path::Path, work_dir: &std::path::Path) -> Result<(), String> {
+    let file = std::fs::File::open(archive).map_err(|e| format!("Failed to open image archive: {}", e))?;
+    tar::Archive::new(file)
+        .unpack(work_dir)
+        .map_err(|e| format!("Failed to unpack image archive: {}", e))?;
+
+    // Daemons using the containerd image store already emit an OCI layout
+    if work_dir.join("oci-layout").exists() && work_dir.join("index.json").exists() {
+        std::fs::copy(archive, output).map_err(|e| format!("Failed to write OCI archive: {}", e))?;
+        return Ok(());
+    }
+
+    let manifest_bytes = std::fs::read(work_dir.join("manifest.json"))
+        .map_err(|e| format!("Image archive has no manifest.json: {}", e))?;
+    let archive_manifests: Vec<DockerArchiveManifest> = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| format!("Failed to parse manifest.json: {}", e))?;
+
+    let output_file = std::fs::File::create(output).map_err(|e| format!("Failed to create output file: {}", e))?;
+    let mut builder = tar::Builder::new(output_file);
+    let mut written = std::collections::HashSet::new();
+    let mut index_manifests = Vec::new();
+
+    for entry in archive_manifests {
+        let (config_digest, config_size) = append_oci_blob_file(&mut builder, &work_dir.join(&entry.config), &mut written)?;
+
+        let mut layers = Vec::with_capacity(entry.layers.len());
+        for layer in &entry.layers {
+            let (digest, size) = append_oci_blob_file(&mut builder, &work_dir.join(layer), &mut written)?;
+            layers.push(serde_json::json!({
+                "mediaType": "application/vnd.oci.image.layer.v1.tar",
+                "digest": digest,
+                "size": size,
+            }));
+        }
+
+        let image_manifest = serde_json::json!({
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.manifest.v1+json",
+            "config": {
+                "mediaType": "application/vnd.oci.image.config.v1+json",
+                "digest": config_digest,
+                "size": config_size,
+            },
+            "layers": layers,
+        });
+        let manifest_data = serde_json::to_vec(&image_manifest)
+            .map_err(|e| format!("Failed to serialize OCI manifest: {}", e))?;
+        let (manifest_digest, manifest_size) = append_oci_blob(&mut builder, &manifest_data, &mut written)?;
+
+        let tags = entry.repo_tags.unwrap_or_default();
+        if tags.is_empty() {
+            index_manifests.push(serde_json::json!({
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": manifest_digest,
+                "size": manifest_size,
+            }));
+        }
+        for tag in tags {
+            index_manifests.push(serde_json::json!({
+                "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                "digest": manifest_digest,
+                "size": manifest_size,
+                "annotations": { "org.opencontainers.image.ref.name": tag },
+            }));
+        }
+    }
+
+    let index = serde_json::json!({ "schemaVersion": 2, "manifests": index_manifests });
+    let layout = serde_json::json!({ "imageLayoutVersion": "1.0.0" });
+
+    for (name, value) in [("index.json", index), ("oci-layout", layout)] {
+        let data = serde_json::to_vec(&value).map_err(|e| format!("Failed to serialize {}: {}", name, e))?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, data.as_slice())
+            .map_err(|e| format!("Failed to write {}: {}", name, e))?;
+    }
+
+    builder.finish().map_err(|e| format!("Failed to finalize OCI archive: {}", e))
+}
+
+static TEMP_DIR_SEQUENCE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+// A path under the system temp dir that no other export, in this or another process, uses
+fn unique_temp_dir(prefix: &str) -> std::path::PathBuf {
+    let sequence = TEMP_DIR_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    std::env::temp_dir().join(format!("{}-{}-{}-{}", prefix, std::process::id(), nanos, sequence))
+}
+
+// Unlike `save_image`, which writes Docker's legacy archive format (manifest.json plus
+// per-layer directories), this produces an OCI image layout (oci-layout, index.json and
+// content-addressed blobs) that containerd, cosign, skopeo and friends consume directly
+#[tauri::command]
+async fn export_oci(
+    image_id: String,
+    output_path: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    use tokio::io::AsyncWriteExt;
+
+    let docker = docker_client.get().await?;
+
+    let work_dir = unique_temp_dir("vessel-oci");
+    tokio::fs::create_dir_all(&work_dir)
+        .await
+        .map_err(|e| format!("Failed to create working directory: {}", e))?;
+    let archive_path = work_dir.join("docker-image.tar");
+
+    let export_result: Result<(), String> = async {
+        let mut file = tokio::fs::File::create(&archive_path)
+            .await
+            .map_err(|e| format!("Failed to create temporary archive: {}", e))?;
+
+        let mut export_stream = docker.export_image(&image_id);
+        while let Some(chunk) = export_stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Failed to export image: {}", e))?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| format!("Failed to write temporary archive: {}", e))?;
+        }
+        file.flush().await.map_err(|e| format!("Failed to flush temporary archive: {}", e))?;
+
+        let archive = archive_path.clone();
+        let unpack_dir = work_dir.join("unpacked");
+        let output = std::path::PathBuf::from(&output_path);
+        tokio::task::spawn_blocking(move || docker_archive_to_oci(&archive, &output, &unpack_dir))
+            .await
+            .map_err(|e| format!("OCI conversion task failed: {}", e))?
+    }
+    .await;
+
+    let _ = tokio::fs::remove_dir_all(&work_dir).await;
+    export_result?;
+
+    Ok(format!("Image {} exported as an OCI archive to {}", image_id, output_path))
+}
+
+fn volume_info_from_volume(volume: bollard::models::Volume, size: Option<u64>) -> VolumeInfo {
+    VolumeInfo {
+        name: volume.name,
+        driver: volume.driver,
+        mountpoint: volume.mountpoint,
+        created_at: volume.created_at,
+        labels: volume.labels,
+        options: volume.options,
+        scope: volume.scope.map(|s| s.to_string()).unwrap_or_else(|| "local".to_string()),
+        size,
+    }
+}
+
+async fn fetch_volumes(docker: &Docker, dangling: Option<bool>) -> Result<Vec<bollard::models::Volume>, String> {
+    let mut filters = HashMap::new();
+    if let Some(dangling) = dangling {
+        filters.insert("dangling".to_string(), vec![dangling.to_string()]);
+    }
+
+    let options = ListVolumesOptions::<String> {
+        filters,
+    };
+
+    let volumes_response = docker
+        .list_volumes(Some(options))
+        .await
+        .map_err(|e| format!("Failed to list volumes: {}", e))?;
+
+    Ok(volumes_response.volumes.unwrap_or_default())
+}
+
+// Number of largest files reported by `build_context_size`
+const LARGEST_CONTEXT_FILES: usize = 10;
+
+// Exclusion rules from a build context's .dockerignore. Later rules win, `!` re-includes,
+// and a rule matching a directory excludes everything beneath it.
+struct DockerIgnore {
+    rules: Vec<(Regex, bool)>,
+}
+
+impl DockerIgnore {
+    fn load(context: &std::path::Path) -> Self {
+        let contents = std::fs::read_to_string(context.join(".dockerignore")).unwrap_or_default();
+        let rules = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (pattern, excludes) = match line.strip_prefix('!') {
+                    Some(rest) => (rest.trim(), false),
+                    None => (line, true),
+                };
+                let pattern = pattern.trim_start_matches("./").trim_matches('/');
+                Regex::new(&Self::pattern_to_regex(pattern)).ok().map(|regex| (regex, excludes))
+            })
+            .collect();
+
+        DockerIgnore { rules }
+    }
+
+    fn pattern_to_regex(pattern: &str) -> String {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    // "**/" also matches zero directories
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        regex.push_str("(?:.*/)?");
+                    } else {
+                        regex.push_str(".*");
+                    }
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+        regex
+    }
+
+    fn has_negations(&self) -> bool {
+        self.rules.iter().any(|(_, excludes)| !excludes)
+    }
+
+    // `path` is relative to the context root and uses '/' separators
+    fn is_excluded(&self, path: &str) -> bool {
+        let mut excluded = false;
+        for (regex, excludes) in &self.rules {
+            let mut prefix_end = path.len();
+            let matched = loop {
+                if regex.is_match(&path[..prefix_end]) {
+                    break true;
+                }
+                match path[..prefix_end].rfind('/') {
+                    Some(pos) => prefix_end = pos,
+                    None => break false,
+                }
+            };
+            if matched {
+                excluded = *excludes;
+            }
+        }
+        excluded
+    }
+}
+
+// Walk a build context, calling `visit` with the relative path and metadata of every file
+// and directory that survives .dockerignore. A directory is visited before its contents.
+fn walk_build_context(
+    root: &std::path::Path,
+    ignore: &DockerIgnore,
+    visit: &mut dyn FnMut(&str, &std::path::Path, &std::fs::Metadata) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = std::fs::read_dir(&dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+            let relative = path
+                .strip_prefix(root)
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+
+            let metadata = std::fs::symlink_metadata(&path).map_err(|e| format!("Failed to stat {}: {}", path.display(), e))?;
+            if metadata.is_dir() {
+                let excluded = ignore.is_excluded(&relative);
+                if !excluded {
+                    visit(&relative, &path, &metadata)?;
+                }
+                // A `!` rule may re-include files beneath an excluded directory
+                if ignore.has_negations() || !excluded {
+                    pending.push(path);
+                }
+            } else if !ignore.is_excluded(&relative) {
+                visit(&relative, &path, &metadata)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn build_context_size(context_path: String) -> Result<BuildContextSize, String> {
+    let root = std::path::PathBuf::from(&context_path);
+    if !root.is_dir() {
+        return Err(format!("Build context '{}' is not a directory", context_path));
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let ignore = DockerIgnore::load(&root);
+        let mut total_bytes = 0u64;
+        let mut file_count = 0u64;
+        let mut files: Vec<(String, u64)> = Vec::new();
+
+        walk_build_context(&root, &ignore, &mut |relative, _, metadata| {
+            if metadata.is_dir() {
+                return Ok(());
+            }
+            total_bytes += metadata.len();
+            file_count += 1;
+            files.push((relative.to_string(), metadata.len()));
+            Ok(())
+        })?;
+
+        files.sort_by(|a, b| b.1.cmp(&a.1));
+        files.truncate(LARGEST_CONTEXT_FILES);
+
+        Ok(BuildContextSize {
+            total_bytes,
+            file_count,
+            largest_files: files,
+        })
+    })
+    .await
+    .map_err(|e| format!("Build context scan failed: {}", e))?
+}
+
+// Tar the build context in memory, honouring .dockerignore. The Dockerfile is always sent,
+// even when ignored, since the daemon can't build without it.
+fn build_context_tar(root: &std::path::Path, dockerfile: &str) -> Result<Vec<u8>, String> {
+    let ignore = DockerIgnore::load(root);
+    let mut builder = tar::Builder::new(Vec::new());
+    // Keep symlinks as links, like the docker CLI does
+    builder.follow_symlinks(false);
+
+    let mut has_dockerfile = false;
+    walk_build_context(root, &ignore, &mut |relative, path, metadata| {
+        // Directories get their own entries so empty ones and their modes survive, as with the docker CLI
+        if metadata.is_dir() {
+            return builder
+                .append_dir(relative, path)
+                .map_err(|e| format!("Failed to add {} to build context: {}", relative, e));
+        }
+        has_dockerfile |= relative == dockerfile;
+        builder
+            .append_path_with_name(path, relative)
+            .map_err(|e| format!("Failed to add {} to build context: {}", relative, e))
+    })?;
+
+    if !has_dockerfile {
+        let path = root.join(dockerfile);
+        if !path.is_file() {
+            return Err(format!("Dockerfile '{}' not found in build context", dockerfile));
+        }
+        builder
+            .append_path_with_name(&path, dockerfile)
+            .map_err(|e| format!("Failed to add {} to build context: {}", dockerfile, e))?;
+    }
+
+    builder.into_inner().map_err(|e| format!("Failed to finish build context: {}", e))
+}
+
+// `docker build`: every chunk of build output goes out on `image-build-{tag}`, followed by
+// `image-build-complete-{tag}` with the image id or `image-build-error-{tag}`
+#[tauri::command]
+async fn build_image(
+    context_path: String,
+    dockerfile: Option<String>,
+    tag: String,
+    build_args: HashMap<String, String>,
+    app_handle: tauri::AppHandle,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let root = std::path::PathBuf::from(&context_path);
+    if !root.is_dir() {
+        return Err(format!("Build context '{}' is not a directory", context_path));
+    }
+    if tag.trim().is_empty() {
+        return Err("A tag is required to build an image".to_string());
+    }
+    parse_image_reference(&tag)?;
+
+    let dockerfile = dockerfile
+        .map(|path| path.trim().replace('\\', "/").trim_start_matches("./").to_string())
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| "Dockerfile".to_string());
+    if std::path::Path::new(&dockerfile).is_absolute() || dockerfile.split('/').any(|part| part == "..") {
+        return Err(format!("Dockerfile '{}' must be a path inside the build context", dockerfile));
+    }
+
+    let docker = docker_client.get().await?;
+    let event_tag = event_safe(&tag);
+
+    let result = stream_build(&docker, root, &dockerfile, &tag, build_args, credentials.all(), &app_handle, &event_tag).await;
+    match &result {
+        Ok(image_id) => {
+            let _ = app_handle.emit(&format!("image-build-complete-{}", event_tag), image_id.clone());
+        }
+        Err(e) => {
+            let _ = app_handle.emit(&format!("image-build-error-{}", event_tag), e.clone());
+        }
+    }
+
+    result
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn stream_build(
+    docker: &Docker,
+    root: std::path::PathBuf,
+    dockerfile: &str,
+    tag: &str,
+    build_args: HashMap<String, String>,
+    credentials: HashMap<String, bollard::auth::DockerCredentials>,
+    app_handle: &tauri::AppHandle,
+    event_tag: &str,
+) -> Result<String, String> {
+    let context_dockerfile = dockerfile.to_string();
+    let context = tokio::task::spawn_blocking(move || build_context_tar(&root, &context_dockerfile))
+        .await
+        .map_err(|e| format!("Failed to package build context: {}", e))??;
+
+    let options = bollard::image::BuildImageOptions {
+        dockerfile: dockerfile.to_string(),
+        t: tag.to_string(),
+        buildargs: build_args,
+        rm: true,
+        ..Default::default()
+    };
+
+    let event_name = format!("image-build-{}", event_tag);
+    let emit = |kind: &str, message: String| {
+        let _ = app_handle.emit(&event_name, ImageBuildEvent { kind: kind.to_string(), message });
+    };
+
+    let credentials = if credentials.is_empty() { None } else { Some(credentials) };
+    let mut build_stream = docker.build_image(options, credentials, Some(context.into()));
+    let mut image_id = None;
+
+    while let Some(result) = build_stream.next().await {
+        let info = result.map_err(|e| format!("Failed to build image {}: {}", tag, e))?;
+
+        if let Some(error) = info.error {
+            emit("error", error.clone());
+            return Err(format!("Failed to build image {}: {}", tag, error));
+        }
+        if let Some(stream) = info.stream {
+            emit("stream", stream);
+        }
+        if let Some(status) = info.status {
+            emit("status", status);
+        }
+        if let Some(id) = info.aux.and_then(|aux| aux.id) {
+            emit("image_id", id.clone());
+            image_id = Some(id);
+        }
+    }
+
+    // Older daemons only report the id through the "Successfully built" line, so fall back
+    // to resolving the tag
+    match image_id {
+        Some(id) => Ok(id),
+        None => docker
+            .inspect_image(tag)
+            .await
+            .map_err(|e| format!("Build finished but image {} could not be found: {}", tag, e))?
+            .id
+            .ok_or_else(|| format!("Build finished but image {} has no id", tag)),
+    }
+}
+
+#[tauri::command]
+async fn list_volumes(
+    dangling: Option<bool>,
+    size_cache: tauri::State<'_, VolumeSizeCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<VolumeInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let volumes = fetch_volumes(&docker, dangling).await?;
+
+    // Get all volume sizes using Docker system df
+    let volume_sizes = size_cache.sizes().await.unwrap_or_default();
+
+    let volume_info: Vec<VolumeInfo> = volumes
+        .into_iter()
+        .map(|volume| {
+            // Get size from Docker system df results
+            let size = volume_sizes.get(&volume.name).copied();
+            volume_info_from_volume(volume, size)
+        })
+        .collect();
+
+    Ok(volume_info)
+}
+
+// Skips the `docker system df` size scan so the list renders immediately;
+// sizes can be fetched lazily with `get_volume_size`
+#[tauri::command]
+async fn list_volumes_fast(
+    dangling: Option<bool>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<VolumeInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let volumes = fetch_volumes(&docker, dangling).await?;
+
+    Ok(volumes
+        .into_iter()
+        .map(|volume| volume_info_from_volume(volume, None))
+        .collect())
+}
+
+#[tauri::command]
+async fn create_volume(volume_name: String, docker_client: tauri::State<'_, DockerClient>) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    let config = bollard::volume::CreateVolumeOptions {
+        name: volume_name.clone(),
+        driver: "local".to_string(),
+        ..Default::default()
+    };
+
+    docker
+        .create_volume(config)
+        .await
+        .map_err(|e| format!("Failed to create volume: {}", e))?;
+
+    Ok(format!("Volume {} created successfully", volume_name))
+}
+
+#[tauri::command]
+async fn remove_volume(volume_name: String, docker_client: tauri::State<'_, DockerClient>) -> Result<String, String> {
+    println!("Attempting to remove volume: {}", volume_name);
+    
+    let docker = docker_client
+        .get()
+        .await
+        .map_err(|error_msg| {
+            println!("Docker connection error: {}", error_msg);
+            error_msg
+        })?;
+
+    println!("Connected to Docker, removing volume: {}", volume_name);
+    
+    docker
+        .remove_volume(&volume_name, None)
+        .await
+        .map_err(|e| {
+            let error_msg = format!("Failed to remove volume '{}': {}", volume_name, e);
+            println!("Volume removal error: {}", error_msg);
+            error_msg
+        })?;
+
+    let success_msg = format!("Volume {} removed successfully", volume_name);
+    println!("{}", success_msg);
+    Ok(success_msg)
+}
+
+#[tauri::command]
+async fn get_volume_size(volume_name: String, size_cache: tauri::State<'_, VolumeSizeCache>) -> Result<u64, String> {
+    // Get all volume sizes using Docker system df
+    let volume_sizes = size_cache.sizes().await?;
+    
+    // Return the size for the specific volume
+    Ok(volume_sizes.get(&volume_name).copied().unwrap_or(0))
+}
+
+// How long a `docker system df` volume size scan is reused; the scan walks every volume
+const VOLUME_SIZE_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[derive(Default)]
+pub struct VolumeSizeCache(std::sync::Mutex<Option<(std::time::Instant, HashMap<String, u64>)>>);
+
+impl VolumeSizeCache {
+    async fn sizes(&self) -> Result<HashMap<String, u64>, String> {
+        if let Ok(cached) = self.0.lock() {
+            if let Some((fetched_at, sizes)) = cached.as_ref() {
+                if fetched_at.elapsed() < VOLUME_SIZE_CACHE_TTL {
+                    return Ok(sizes.clone());
+                }
+            }
+        }
+
+        let sizes = get_all_volume_sizes().await?;
+        if let Ok(mut cached) = self.0.lock() {
+            *cached = Some((std::time::Instant::now(), sizes.clone()));
+        }
+        Ok(sizes)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectVolumeUsage {
+    pub volume_count: u64,
+    pub total_size: u64,
+}
+
+// Volume storage per compose project; unlabelled volumes are grouped as "standalone"
+#[tauri::command]
+async fn volume_usage_by_project(
+    size_cache: tauri::State<'_, VolumeSizeCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<HashMap<String, ProjectVolumeUsage>, String> {
+    let docker = docker_client.get().await?;
+
+    let volumes = fetch_volumes(&docker, None).await?;
+    let volume_sizes = size_cache.sizes().await?;
+
+    let mut usage: HashMap<String, ProjectVolumeUsage> = HashMap::new();
+    for volume in volumes {
+        let project = volume
+            .labels
+            .get("com.docker.compose.project")
+            .cloned()
+            .unwrap_or_else(|| "standalone".to_string());
+
+        let entry = usage.entry(project).or_default();
+        entry.volume_count += 1;
+        entry.total_size += volume_sizes.get(&volume.name).copied().unwrap_or(0);
+    }
+
+    Ok(usage)
+}
+
+async fn get_all_volume_sizes() -> Result<HashMap<String, u64>, String> {
+    // Use Docker's system df -v command to get accurate volume sizes
+    let mut cmd = TokioCommand::new("docker");
+    cmd.args(&["system", "df", "-v"]);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let output = cmd.output().await
+        .map_err(|e| format!("Failed to execute docker system df: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Docker system df failed: {}", stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut volume_sizes = HashMap::new();
+    let mut in_volumes_section = false;
+
+    // Parse the output line by line
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        
+        // Look for the volumes section header
+        if trimmed.starts_with("VOLUME NAME") {
+            in_volumes_section = true;
+            continue;
+        }
+        
+        // Skip empty lines or if not in volumes section
+        if !in_volumes_section || trimmed.is_empty() {
+            continue;
+        }
+        
+        // Stop if we hit another section
+        if trimmed.starts_with("Build Cache") || trimmed.starts_with("REPOSITORY") {
+            break;
+        }
+        
+        // Parse volume line: VOLUME_NAME   LINKS   SIZE
+        let parts: Vec<&str> = trimmed.split_whitespace().collect();
+        if parts.len() >= 3 {
+            let volume_name = parts[0];
+            let size_str = parts[2]; // SIZE is the third column
+            
+            // Parse size string (e.g., "1.5GB", "256MB", "0B")
+            if let Ok(size_bytes) = parse_docker_size(size_str) {
+                volume_sizes.insert(volume_name.to_string(), size_bytes);
+            }
+        }
+    }
+
+    Ok(volume_sizes)
+}
+
+fn parse_docker_size(size_str: &str) -> Result<u64, String> {
+    if size_str == "0B" || size_str == "0" {
+        return Ok(0);
+    }
+
+    let size_str = size_str.trim();
+    let (number_part, unit_part) = if let Some(pos) = size_str.find(|c: char| c.is_alphabetic()) {
+        (&size_str[..pos], &size_str[pos..])
+    } else {
+        (size_str, "B")
+    };
+
+    let number: f64 = number_part.parse()
+        .map_err(|_| format!("Invalid number in size: {}", size_str))?;
+
+    let multiplier = match unit_part.to_uppercase().as_str() {
+        "B" => 1,
+        "KB" => 1024,
+        "MB" => 1024 * 1024,
+        "GB" => 1024 * 1024 * 1024,
+        "TB" => 1024_u64.pow(4),
+        _ => return Err(format!("Unknown size unit: {}", unit_part)),
+    };
+
+    Ok((number * multiplier as f64) as u64)
+}
+
+#[tauri::command]
+async fn list_networks(docker_client: tauri::State<'_, DockerClient>) -> Result<Vec<NetworkInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    let options = Some(ListNetworksOptions::<String> {
+        ..Default::default()
+    });
+
+    let networks = docker
+        .list_networks(options)
+        .await
+        .map_err(|e| format!("Failed to list networks: {}", e))?;
+
+    let network_info: Vec<NetworkInfo> = networks
+        .into_iter()
+        .map(|network| {
+            let ipam_config = network
+                .ipam
+                .as_ref()
+                .and_then(|ipam| ipam.config.as_ref())
+                .map(|configs| {
+                    configs
+                        .iter()
+                        .map(|config| IpamConfig {
+                            subnet: config.subnet.clone(),
+                            gateway: config.gateway.clone(),
+                            ip_range: config.ip_range.clone(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let containers = network
+                .containers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, container)| {
+                    (
+                        key,
+                        NetworkContainer {
+                            name: container.name,
+                            endpoint_id: container.endpoint_id,
                             mac_address: container.mac_address,
                             ipv4_address: container.ipv4_address,
                             ipv6_address: container.ipv6_address,
                         },
                     )
                 })
-                .collect();
+                .collect();
+
+            NetworkInfo {
+                id: network.id.unwrap_or_default(),
+                name: network.name.unwrap_or_default(),
+                driver: network.driver.unwrap_or_default(),
+                scope: network.scope.unwrap_or_default(),
+                created: network.created,
+                internal: network.internal.unwrap_or(false),
+                attachable: network.attachable.unwrap_or(false),
+                ingress: network.ingress.unwrap_or(false),
+                ipam: NetworkIpam {
+                    driver: network.ipam.as_ref().and_then(|ipam| ipam.driver.clone()),
+                    config: ipam_config,
+                    options: network
+                        .ipam
+                        .as_ref()
+                        .and_then(|ipam| ipam.options.clone())
+                        .unwrap_or_default(),
+                },
+                containers,
+                options: network.options.unwrap_or_default(),
+                labels: network.labels.unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    Ok(network_info)
+}
+
+// Image used for short-lived helper containers such as connectivity probes
+const HELPER_IMAGE: &str = "busybox:latest";
+
+// `fromImage`/`tag` for pulling `image`. An empty tag makes the daemon pull every tag of
+// the repository, so untagged references resolve to "latest"; digests are passed as the tag.
+fn image_pull_options(image: &str) -> bollard::image::CreateImageOptions<String> {
+    let (from_image, tag) = match image.split_once('@') {
+        Some((name, digest)) => (name.to_string(), digest.to_string()),
+        None => {
+            let (name, tag) = split_image_tag(image);
+            (name.to_string(), tag.to_string())
+        }
+    };
+
+    bollard::image::CreateImageOptions {
+        from_image,
+        tag,
+        ..Default::default()
+    }
+}
+
+// Pull an image only if it isn't already present locally
+async fn ensure_image(
+    docker: &Docker,
+    image: &str,
+    credentials: Option<bollard::auth::DockerCredentials>,
+) -> Result<(), String> {
+    if docker.inspect_image(image).await.is_ok() {
+        return Ok(());
+    }
+
+    let mut pull_stream = docker.create_image(Some(image_pull_options(image)), None, credentials);
+    while let Some(result) = pull_stream.next().await {
+        let info = result.map_err(|e| format!("Failed to pull image {}: {}", image, e))?;
+        // Registry failures can arrive as an error message inside an otherwise successful stream
+        if let Some(error) = info.error {
+            return Err(format!("Failed to pull image {}: {}", image, error));
+        }
+    }
+
+    Ok(())
+}
+
+// Wait for a container to exit and return its exit code
+async fn wait_for_exit(docker: &Docker, container_id: &str) -> Result<i64, String> {
+    wait_for_condition(docker, container_id, None).await
+}
+
+// `condition` is "not-running" (the default), "next-exit" or "removed"
+async fn wait_for_condition(docker: &Docker, container_id: &str, condition: Option<&str>) -> Result<i64, String> {
+    let options = condition.map(|condition| bollard::container::WaitContainerOptions { condition: condition.to_string() });
+    let mut wait_stream = docker.wait_container(container_id, options);
+
+    match wait_stream.next().await {
+        Some(Ok(response)) => Ok(response.status_code),
+        // bollard reports non-zero exit codes as an error
+        Some(Err(bollard::errors::Error::DockerContainerWaitError { code, .. })) => Ok(code),
+        Some(Err(e)) => Err(format!("Failed to wait for container: {}", e)),
+        None => Err("Container wait stream ended unexpectedly".to_string()),
+    }
+}
+
+// Collect a finished container's full stdout and stderr
+async fn collect_container_output(docker: &Docker, container_id: &str) -> Result<(String, String), String> {
+    let logs_options = LogsOptions::<String> {
+        stdout: true,
+        stderr: true,
+        tail: "all".to_string(),
+        ..Default::default()
+    };
+
+    let mut log_stream = docker.logs(container_id, Some(logs_options));
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    while let Some(log_result) = log_stream.next().await {
+        match log_result.map_err(|e| format!("Failed to read container output: {}", e))? {
+            bollard::container::LogOutput::StdErr { message } => stderr.push_str(&String::from_utf8_lossy(&message)),
+            other => stdout.push_str(&String::from_utf8_lossy(&other.into_bytes())),
+        }
+    }
+
+    Ok((stdout, stderr))
+}
+
+// Parse the average round-trip time from busybox ping's summary line
+fn parse_ping_latency(output: &str) -> Option<f64> {
+    output
+        .lines()
+        .find(|line| line.contains("min/avg/max"))
+        .and_then(|line| line.split('=').nth(1))
+        .and_then(|values| values.trim().split('/').nth(1))
+        .and_then(|avg| avg.trim().parse().ok())
+}
+
+async fn run_connectivity_probe(docker: &Docker, container_id: &str) -> Result<ConnectivityResult, String> {
+    docker
+        .start_container(container_id, None::<bollard::container::StartContainerOptions<String>>)
+        .await
+        .map_err(|e| format!("Failed to start probe container: {}", e))?;
+
+    let exit_code = wait_for_exit(docker, container_id).await?;
+    let (stdout, stderr) = collect_container_output(docker, container_id).await?;
+    let output = format!("{}{}", stdout, stderr);
+
+    Ok(ConnectivityResult {
+        reachable: exit_code == 0,
+        latency_ms: parse_ping_latency(&output),
+        output,
+    })
+}
+
+#[tauri::command]
+async fn test_network_connectivity(
+    network_id: String,
+    target: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ConnectivityResult, String> {
+    if target.trim().is_empty() || target.starts_with('-') {
+        return Err(format!("Invalid connectivity target: '{}'", target));
+    }
+
+    let docker = docker_client.get().await?;
+
+    ensure_image(&docker, HELPER_IMAGE, None).await?;
+
+    let config = bollard::container::Config::<String> {
+        image: Some(HELPER_IMAGE.to_string()),
+        cmd: Some(vec![
+            "ping".to_string(),
+            "-c".to_string(),
+            "3".to_string(),
+            "-W".to_string(),
+            "2".to_string(),
+            target.clone(),
+        ]),
+        host_config: Some(bollard::models::HostConfig {
+            network_mode: Some(network_id.clone()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let probe = docker
+        .create_container(None::<bollard::container::CreateContainerOptions<String>>, config)
+        .await
+        .map_err(|e| format!("Failed to create probe container: {}", e))?;
+
+    let result = run_connectivity_probe(&docker, &probe.id).await;
+
+    // Always clean up the helper container, even if the probe failed
+    let _ = docker
+        .remove_container(&probe.id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+        .await;
+
+    result
+}
+
+#[tauri::command]
+async fn create_network(
+    request: CreateNetworkRequest,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    if request.name.trim().is_empty() {
+        return Err("A network name is required".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    // Driver options are passed through verbatim, e.g. com.docker.network.bridge.name
+    let config = bollard::network::CreateNetworkOptions {
+        name: request.name.clone(),
+        driver: request.driver.clone().filter(|d| !d.trim().is_empty()).unwrap_or_else(|| "bridge".to_string()),
+        internal: request.internal,
+        attachable: request.attachable,
+        enable_ipv6: request.ipv6,
+        labels: request.labels,
+        options: request.options,
+        ..Default::default()
+    };
+
+    docker
+        .create_network(config)
+        .await
+        .map_err(|e| format!("Failed to create network: {}", e))?;
+
+    Ok(format!("Network {} created successfully", request.name))
+}
+
+// Disconnecting and reconnecting refreshes the container's DNS and routing on each
+// network, which clears stale resolution without restarting the container
+#[tauri::command]
+async fn reconnect_container_networks(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<BulkResult>, String> {
+    let docker = docker_client.get().await?;
+
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+
+    let networks = inspect_result
+        .network_settings
+        .and_then(|settings| settings.networks)
+        .unwrap_or_default();
+
+    let mut results = Vec::with_capacity(networks.len());
+
+    for (network, endpoint) in networks {
+        // The host and none networks can't be detached from a container
+        if network == "host" || network == "none" {
+            results.push(BulkResult {
+                id: network,
+                success: false,
+                message: "Skipped: host and none networks can't be reconnected".to_string(),
+            });
+            continue;
+        }
+
+        // Keep what the user configured (aliases, static IPs, links) for the reconnect
+        let endpoint_config = bollard::models::EndpointSettings {
+            aliases: endpoint.aliases,
+            ipam_config: endpoint.ipam_config,
+            links: endpoint.links,
+            driver_opts: endpoint.driver_opts,
+            ..Default::default()
+        };
+
+        if let Err(e) = docker
+            .disconnect_network(
+                &network,
+                bollard::network::DisconnectNetworkOptions {
+                    container: container_id.clone(),
+                    force: false,
+                },
+            )
+            .await
+        {
+            results.push(BulkResult {
+                id: network,
+                success: false,
+                message: format!("Failed to disconnect: {}", e),
+            });
+            continue;
+        }
+
+        let result = match docker
+            .connect_network(
+                &network,
+                bollard::network::ConnectNetworkOptions {
+                    container: container_id.clone(),
+                    endpoint_config,
+                },
+            )
+            .await
+        {
+            Ok(_) => BulkResult {
+                id: network,
+                success: true,
+                message: "Reconnected".to_string(),
+            },
+            Err(e) => BulkResult {
+                id: network,
+                success: false,
+                message: format!("Disconnected but failed to reconnect: {}", e),
+            },
+        };
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+async fn remove_network(network_id: String, docker_client: tauri::State<'_, DockerClient>) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    docker
+        .remove_network(&network_id)
+        .await
+        .map_err(|e| format!("Failed to remove network: {}", e))?;
+
+    Ok(format!("Network {} removed successfully", network_id))
+}
+
+// Working directory of each terminal session, keyed by session id. Commands run with
+// `current_dir` from here so sessions never touch the process-wide cwd or each other.
+#[derive(Default)]
+pub struct TerminalSessions(std::sync::Mutex<HashMap<String, std::path::PathBuf>>);
+
+impl TerminalSessions {
+    // New sessions start in the home directory
+    fn working_directory(&self, session_id: &str) -> std::path::PathBuf {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.get(session_id).cloned())
+            .or_else(home_directory)
+            .unwrap_or_else(|| std::path::PathBuf::from("/"))
+    }
+
+    fn set_working_directory(&self, session_id: &str, path: std::path::PathBuf) {
+        if let Ok(mut sessions) = self.0.lock() {
+            sessions.insert(session_id.to_string(), path);
+        }
+    }
+
+    // Resolve `path` against the session's current directory and move the session there
+    fn change_dir(&self, session_id: &str, path: &str) -> Result<std::path::PathBuf, String> {
+        let base = self.working_directory(session_id);
+        let resolved = resolve_session_path(&base, path)?;
+        self.set_working_directory(session_id, resolved.clone());
+        Ok(resolved)
+    }
+}
+
+fn home_directory() -> Option<std::path::PathBuf> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .map(std::path::PathBuf::from)
+}
+
+// Resolve `path` against a session's directory, expanding a leading `~`
+fn resolve_session_path(base: &std::path::Path, path: &str) -> Result<std::path::PathBuf, String> {
+    let path = path.trim();
+    let expanded = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') || rest.starts_with('\\') => {
+            let home = home_directory().ok_or_else(|| "Failed to get home directory".to_string())?;
+            home.join(rest.trim_start_matches(['/', '\\']))
+        }
+        _ => base.join(path),
+    };
+
+    let resolved = expanded
+        .canonicalize()
+        .map_err(|e| format!("Failed to change directory: {}: {}", path, e))?;
+    if !resolved.is_dir() {
+        return Err(format!("Failed to change directory: {} is not a directory", path));
+    }
+    Ok(resolved)
+}
+
+#[tauri::command]
+async fn execute_command(
+    session_id: String,
+    command: String,
+    sessions: tauri::State<'_, TerminalSessions>,
+) -> Result<TerminalOutput, String> {
+    // Parse the command string into command and arguments, honoring quotes and escapes
+    let parts = shell_words::split(command.trim()).map_err(|e| format!("Invalid command: {}", e))?;
+    if parts.is_empty() {
+        return Err("Empty command".to_string());
+    }
+
+    let cmd = &parts[0];
+    let args = &parts[1..];
+
+    // Create the command
+    let mut tokio_cmd = TokioCommand::new(cmd);
+    tokio_cmd.args(args);
+    tokio_cmd.current_dir(sessions.working_directory(&session_id));
+    tokio_cmd.stdout(Stdio::piped());
+    tokio_cmd.stderr(Stdio::piped());
+
+    // Execute the command
+    match tokio_cmd.output().await {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let exit_code = output.status.code();
+            let success = output.status.success();
+
+            Ok(TerminalOutput {
+                stdout,
+                stderr,
+                exit_code,
+                success,
+            })
+        }
+        Err(e) => Err(format!("Failed to execute command: {}", e)),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandOutputLine {
+    // "stdout" or "stderr"
+    pub stream: String,
+    pub line: String,
+}
+
+// Cancellation tokens of the streaming command running in each terminal session. Each entry
+// carries a sequence number so a finished command only removes its own entry.
+#[derive(Default)]
+pub struct RunningCommands(std::sync::Mutex<HashMap<String, (u64, CancellationToken)>>);
+
+static COMMAND_SEQUENCE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+impl RunningCommands {
+    fn remove(&self, session_id: &str, sequence: u64) {
+        if let Ok(mut running) = self.0.lock() {
+            if running.get(session_id).is_some_and(|(current, _)| *current == sequence) {
+                running.remove(session_id);
+            }
+        }
+    }
+}
+
+fn forward_command_lines<R>(
+    reader: Option<R>,
+    stream: &'static str,
+    app_handle: tauri::AppHandle,
+    event_name: String,
+) -> tokio::task::JoinHandle<()>
+where
+    R: tokio::io::AsyncRead + Unpin + Send + 'static,
+{
+    use tokio::io::AsyncBufReadExt;
+
+    tokio::spawn(async move {
+        let Some(reader) = reader else { return };
+        let mut lines = tokio::io::BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let _ = app_handle.emit(&event_name, CommandOutputLine {
+                stream: stream.to_string(),
+                line,
+            });
+        }
+    })
+}
+
+// Runs `command` in the session's directory and emits each output line on
+// `command-output-{session_id}` as it arrives, then the exit code on `command-exit-{session_id}`
+#[tauri::command]
+async fn start_command_stream(
+    session_id: String,
+    command: String,
+    app_handle: tauri::AppHandle,
+    sessions: tauri::State<'_, TerminalSessions>,
+    running: tauri::State<'_, RunningCommands>,
+    cancellation: tauri::State<'_, StreamCancellation>,
+) -> Result<String, String> {
+    let parts = shell_words::split(command.trim()).map_err(|e| format!("Invalid command: {}", e))?;
+    if parts.is_empty() {
+        return Err("Empty command".to_string());
+    }
+
+    let mut child = TokioCommand::new(&parts[0])
+        .args(&parts[1..])
+        .current_dir(sessions.working_directory(&session_id))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to execute command: {}", e))?;
+
+    let token = cancellation.token().child_token();
+    let sequence = COMMAND_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    if let Ok(mut running) = running.0.lock() {
+        // One streaming command per session; starting another kills the previous one
+        if let Some((_, previous)) = running.insert(session_id.clone(), (sequence, token.clone())) {
+            previous.cancel();
+        }
+    }
+
+    let output_event = format!("command-output-{}", event_safe(&session_id));
+    let exit_event = format!("command-exit-{}", event_safe(&session_id));
+    let readers = [
+        forward_command_lines(child.stdout.take(), "stdout", app_handle.clone(), output_event.clone()),
+        forward_command_lines(child.stderr.take(), "stderr", app_handle.clone(), output_event),
+    ];
+
+    tokio::spawn(async move {
+        let status = tokio::select! {
+            _ = token.cancelled() => None,
+            status = child.wait() => Some(status),
+        };
+        let exit_code = match status {
+            Some(status) => status.ok().and_then(|status| status.code()),
+            None => {
+                let _ = child.kill().await;
+                None
+            }
+        };
+
+        app_handle.state::<RunningCommands>().remove(&session_id, sequence);
+
+        // Deliver the last lines before announcing the exit
+        futures_util::future::join_all(readers).await;
+        let _ = app_handle.emit(&exit_event, exit_code);
+    });
+
+    Ok(format!("Started '{}'", command.trim()))
+}
+
+#[tauri::command]
+async fn kill_command(session_id: String, running: tauri::State<'_, RunningCommands>) -> Result<String, String> {
+    let token = running.0.lock().ok().and_then(|mut running| running.remove(&session_id));
+
+    match token {
+        Some((_, token)) => {
+            token.cancel();
+            Ok(format!("Killed running command in session {}", session_id))
+        }
+        None => Err(format!("No command is running in session {}", session_id)),
+    }
+}
+
+#[tauri::command]
+async fn get_current_directory(
+    session_id: String,
+    sessions: tauri::State<'_, TerminalSessions>,
+) -> Result<String, String> {
+    Ok(sessions.working_directory(&session_id).to_string_lossy().to_string())
+}
+
+#[tauri::command]
+async fn get_home_directory() -> Result<String, String> {
+    match home_directory() {
+        Some(home) => Ok(home.to_string_lossy().to_string()),
+        None => Err("Failed to get home directory".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn set_working_directory(
+    session_id: String,
+    path: String,
+    sessions: tauri::State<'_, TerminalSessions>,
+) -> Result<String, String> {
+    let resolved = sessions.change_dir(&session_id, &path)?;
+
+    Ok(format!("Working directory set to {} for session {}", resolved.display(), session_id))
+}
+
+#[tauri::command]
+async fn change_directory(
+    session_id: String,
+    path: String,
+    sessions: tauri::State<'_, TerminalSessions>,
+) -> Result<String, String> {
+    let resolved = sessions.change_dir(&session_id, &path)?;
+
+    Ok(format!("Changed directory to: {}", resolved.display()))
+}
+
+#[tauri::command]
+async fn execute_docker_command(args: Vec<String>) -> Result<TerminalOutput, String> {
+    let mut cmd = TokioCommand::new("docker");
+    cmd.args(&args);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    match cmd.output().await {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let exit_code = output.status.code();
+            let success = output.status.success();
+
+            Ok(TerminalOutput {
+                stdout,
+                stderr,
+                exit_code,
+                success,
+            })
+        }
+        Err(e) => Err(format!("Failed to execute docker command: {}", e)),
+    }
+}
+
+// A `System` kept for the app's lifetime. CPU usage is the delta between two refreshes, so
+// reusing it means regular polls get usage over the whole poll period without waiting.
+#[derive(Default, Clone)]
+pub struct SystemMonitor(std::sync::Arc<std::sync::Mutex<(System, Option<std::time::Instant>)>>);
+
+// Filesystems backed by memory or by another mount rather than by storage of their own
+const PSEUDO_FILESYSTEMS: &[&str] = &[
+    "tmpfs", "devtmpfs", "overlay", "squashfs", "proc", "sysfs", "cgroup", "cgroup2", "devfs", "autofs",
+    "ramfs", "nsfs", "fuse.lxcfs",
+];
+
+// May block for up to sysinfo's minimum CPU sampling interval; call from a blocking task
+fn read_system_stats(monitor: &SystemMonitor) -> SystemStats {
+    let mut guard = monitor.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (sys, last_cpu_refresh) = &mut *guard;
+
+    // Without a recent enough previous refresh there is nothing to diff against yet
+    let since_last = last_cpu_refresh.map(|at| at.elapsed());
+    match since_last {
+        Some(elapsed) if elapsed >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL => {}
+        Some(elapsed) => std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL - elapsed),
+        None => {
+            sys.refresh_cpu();
+            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        }
+    }
+    sys.refresh_cpu();
+    sys.refresh_memory();
+    *last_cpu_refresh = Some(std::time::Instant::now());
+    
+    let cpu_usage = sys.global_cpu_info().cpu_usage();
+    let memory_used = sys.used_memory();
+    let memory_total = sys.total_memory();
+    
+    // Pseudo filesystems don't hold real storage. A block device can be mounted several
+    // times (bind mounts, btrfs subvolumes), so it's counted once, while anything without a
+    // device path is keyed by where it is mounted.
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let mut seen_disks = std::collections::HashSet::new();
+    let (mut disk_used, mut disk_total) = (0u64, 0u64);
+    for disk in disks.list() {
+        let file_system = disk.file_system().to_string_lossy().to_ascii_lowercase();
+        if disk.total_space() == 0 || PSEUDO_FILESYSTEMS.contains(&file_system.as_str()) {
+            continue;
+        }
+        let key = if std::path::Path::new(disk.name()).starts_with("/dev") {
+            disk.name().to_os_string()
+        } else {
+            disk.mount_point().as_os_str().to_os_string()
+        };
+        if !seen_disks.insert(key) {
+            continue;
+        }
+        disk_total += disk.total_space();
+        disk_used += disk.total_space().saturating_sub(disk.available_space());
+    }
+    
+    let cpu_count = sys.cpus().len();
+    let per_core_usage = sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+
+    let load_average = if cfg!(windows) {
+        None
+    } else {
+        let load = System::load_average();
+        Some([load.one, load.five, load.fifteen])
+    };
+    
+    SystemStats {
+        cpu_usage,
+        memory_used,
+        memory_total,
+        memory_used_gb: memory_used as f64 / 1_073_741_824.0, // Convert to GB
+        memory_total_gb: memory_total as f64 / 1_073_741_824.0,
+        disk_used,
+        disk_total,
+        disk_used_gb: disk_used as f64 / 1_073_741_824.0,
+        disk_total_gb: disk_total as f64 / 1_073_741_824.0,
+        cpu_count,
+        per_core_usage,
+        swap_used: sys.used_swap(),
+        swap_total: sys.total_swap(),
+        load_average,
+    }
+}
+
+#[tauri::command]
+async fn get_system_stats(monitor: tauri::State<'_, SystemMonitor>) -> Result<SystemStats, String> {
+    let monitor = monitor.inner().clone();
+    tokio::task::spawn_blocking(move || read_system_stats(&monitor))
+        .await
+        .map_err(|e| format!("Failed to read system stats: {}", e))
+}
+
+// Lines returned by `get_daemon_logs` when no tail is given
+const DEFAULT_DAEMON_LOG_TAIL: u64 = 200;
+
+// Where Docker Desktop writes the daemon's log inside its data directory
+fn desktop_daemon_log_paths() -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+
+    if cfg!(target_os = "macos") {
+        if let Ok(home) = std::env::var("HOME") {
+            let data = std::path::Path::new(&home).join("Library/Containers/com.docker.docker/Data/log");
+            paths.push(data.join("vm/dockerd.log"));
+            paths.push(data.join("vm/docker.log"));
+        }
+    } else if cfg!(windows) {
+        if let Ok(local_app_data) = std::env::var("LOCALAPPDATA") {
+            let data = std::path::Path::new(&local_app_data).join("Docker/log");
+            paths.push(data.join("vm/dockerd.log"));
+            paths.push(data.join("vm/docker.log"));
+        }
+    } else {
+        // Docker Desktop for Linux, and daemons logging to a file instead of journald
+        if let Ok(home) = std::env::var("HOME") {
+            paths.push(std::path::Path::new(&home).join(".docker/desktop/log/vm/dockerd.log"));
+        }
+        paths.push(std::path::PathBuf::from("/var/log/docker.log"));
+    }
+
+    paths
+}
+
+async fn journal_daemon_logs(tail: u64) -> Option<String> {
+    let output = TokioCommand::new("journalctl")
+        .args(["-u", "docker.service", "--no-pager", "-n", &tail.to_string()])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .ok()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    // journalctl prints "-- No entries --" when the unit is unknown or unreadable
+    if output.status.success() && !stdout.trim().is_empty() && !stdout.contains("-- No entries --") {
+        Some(stdout)
+    } else {
+        None
+    }
+}
+
+#[tauri::command]
+async fn get_daemon_logs(tail: Option<u64>) -> Result<String, String> {
+    let tail = tail.filter(|t| *t > 0).unwrap_or(DEFAULT_DAEMON_LOG_TAIL);
+
+    if cfg!(target_os = "linux") {
+        if let Some(logs) = journal_daemon_logs(tail).await {
+            return Ok(logs);
+        }
+    }
+
+    let paths = desktop_daemon_log_paths();
+    for path in &paths {
+        match tokio::fs::read_to_string(path).await {
+            Ok(contents) => {
+                let lines: Vec<&str> = contents.lines().collect();
+                let start = lines.len().saturating_sub(tail as usize);
+                return Ok(lines[start..].join("\n"));
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                return Err(format!("Permission denied reading daemon log {}", path.display()));
+            }
+            Err(_) => continue,
+        }
+    }
+
+    let searched: Vec<String> = paths.iter().map(|path| path.display().to_string()).collect();
+    if cfg!(target_os = "linux") {
+        Err(format!(
+            "Docker daemon logs are not accessible: journalctl returned nothing for docker.service (the user may need to be in the systemd-journal group) and no log file was found at {}",
+            searched.join(", ")
+        ))
+    } else {
+        Err(format!("Docker daemon log not found. Looked in {}", searched.join(", ")))
+    }
+}
+
+#[tauri::command]
+async fn get_docker_system_info(docker_client: tauri::State<'_, DockerClient>) -> Result<DockerSystemInfo, String> {
+    let docker = docker_client.get().await?;
+
+    collect_docker_system_info(&docker).await
+}
+
+async fn collect_docker_system_info(docker: &Docker) -> Result<DockerSystemInfo, String> {
+    // Get containers
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let containers_total = containers.len();
+    let containers_running = containers
+        .iter()
+        .filter(|c| c.state.as_ref().map(|s| s == "running").unwrap_or(false))
+        .count();
+    let containers_stopped = containers_total - containers_running;
+
+    // Get images
+    let images = docker
+        .list_images(Some(ListImagesOptions::<String> {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list images: {}", e))?;
+
+    // Get volumes
+    let volumes_response = docker
+        .list_volumes(Some(ListVolumesOptions::<String> {
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list volumes: {}", e))?;
+
+    let volumes_total = volumes_response.volumes.unwrap_or_default().len();
+
+    // Get networks
+    let networks = docker
+        .list_networks(Some(ListNetworksOptions::<String> {
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list networks: {}", e))?;
+
+    Ok(DockerSystemInfo {
+        containers_running,
+        containers_stopped,
+        containers_total,
+        images_total: images.len(),
+        volumes_total,
+        networks_total: networks.len(),
+    })
+}
+
+async fn ensure_swarm_mode(docker: &Docker) -> Result<(), String> {
+    let info = docker
+        .info()
+        .await
+        .map_err(|e| format!("Failed to get Docker info: {}", e))?;
+
+    let swarm_active = info
+        .swarm
+        .and_then(|swarm| swarm.local_node_state)
+        .map(|state| state == bollard::models::LocalNodeState::ACTIVE)
+        .unwrap_or(false);
+
+    if swarm_active {
+        Ok(())
+    } else {
+        Err("Docker is not running in swarm mode. Run 'docker swarm init' or join a swarm first.".to_string())
+    }
+}
+
+#[tauri::command]
+async fn list_nodes(docker_client: tauri::State<'_, DockerClient>) -> Result<Vec<SwarmNodeInfo>, String> {
+    let docker = docker_client.get().await?;
+
+    ensure_swarm_mode(&docker).await?;
+
+    let nodes = docker
+        .list_nodes(None::<bollard::node::ListNodesOptions<String>>)
+        .await
+        .map_err(|e| format!("Failed to list nodes: {}", e))?;
+
+    let node_info: Vec<SwarmNodeInfo> = nodes
+        .into_iter()
+        .map(|node| {
+            let spec = node.spec.unwrap_or_default();
+            let description = node.description.unwrap_or_default();
+
+            SwarmNodeInfo {
+                id: node.id.unwrap_or_default(),
+                hostname: description.hostname.unwrap_or_else(|| "unknown".to_string()),
+                role: spec.role.map(|r| r.to_string()).unwrap_or_else(|| "unknown".to_string()),
+                availability: spec.availability.map(|a| a.to_string()).unwrap_or_else(|| "unknown".to_string()),
+                status: node
+                    .status
+                    .and_then(|status| status.state)
+                    .map(|state| state.to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                engine_version: description.engine.and_then(|engine| engine.engine_version),
+                leader: node
+                    .manager_status
+                    .and_then(|manager| manager.leader)
+                    .unwrap_or(false),
+            }
+        })
+        .collect();
+
+    Ok(node_info)
+}
+
+#[tauri::command]
+async fn remove_container(
+    container_id: String,
+    force: Option<bool>,
+    remove_volumes: Option<bool>,
+    remove_links: Option<bool>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    // `v` deletes the container's anonymous volumes, `link` removes a legacy link by its name
+    let options = Some(RemoveContainerOptions {
+        force: force.unwrap_or(false),
+        v: remove_volumes.unwrap_or(false),
+        link: remove_links.unwrap_or(false),
+    });
+
+    docker
+        .remove_container(&container_id, options)
+        .await
+        .map_err(|e| format!("Failed to remove container: {}", e))?;
+
+    Ok(format!("Container {} removed successfully", container_id))
+}
+
+#[tauri::command]
+async fn stop_and_remove_container(
+    container_id: String,
+    timeout_secs: Option<i64>,
+    remove_volumes: bool,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    let stop_options = timeout_secs.map(|t| bollard::container::StopContainerOptions { t });
+    match docker.stop_container(&container_id, stop_options).await {
+        Ok(_) => {}
+        // 304 means the container was already stopped
+        Err(bollard::errors::Error::DockerResponseServerError { status_code: 304, .. }) => {}
+        Err(e) => return Err(format!("Failed to stop container: {}", e)),
+    }
+
+    // `v` also deletes the container's anonymous volumes; named volumes are never touched
+    let options = Some(RemoveContainerOptions {
+        v: remove_volumes,
+        ..Default::default()
+    });
+
+    docker
+        .remove_container(&container_id, options)
+        .await
+        .map_err(|e| format!("Failed to remove container: {}", e))?;
+
+    if remove_volumes {
+        Ok(format!("Container {} stopped and removed along with its anonymous volumes", container_id))
+    } else {
+        Ok(format!("Container {} stopped and removed", container_id))
+    }
+}
+
+// Remove a container and, if nothing else needs it any more, the image it was created from
+#[tauri::command]
+async fn purge_container_and_image(
+    container_id: String,
+    force_image: bool,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<PurgeResult, String> {
+    let docker = docker_client.get().await?;
+
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+    let image_id = inspect_result.image;
+    let running = inspect_result
+        .state
+        .map(|state| state.running == Some(true) || state.restarting == Some(true))
+        .unwrap_or(false);
+
+    // Stop with the normal grace period so the process can shut down cleanly, rather than
+    // having a forced remove kill it
+    if running {
+        match docker.stop_container(&container_id, None).await {
+            Ok(_) => {}
+            // 304 means the container stopped in the meantime
+            Err(bollard::errors::Error::DockerResponseServerError { status_code: 304, .. }) => {}
+            Err(e) => return Err(format!("Failed to stop container: {}", e)),
+        }
+    }
+
+    docker
+        .remove_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to remove container: {}", e))?;
+    let removed_message = if running {
+        format!("Container {} stopped and removed", container_id)
+    } else {
+        format!("Container {} removed", container_id)
+    };
+
+    let Some(image) = image_id.clone() else {
+        return Ok(PurgeResult {
+            container_id: container_id.clone(),
+            stopped: running,
+            image_id: None,
+            image_removed: false,
+            message: format!("{}; it had no image to clean up", removed_message),
+        });
+    };
+
+    let keep_reason = if images_in_use(&docker).await?.contains(&image) {
+        Some("it is still used by other containers")
+    } else {
+        // Locally built images record their base as parent
+        let images = docker
+            .list_images(Some(ListImagesOptions::<String> {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+            .map_err(|e| format!("Failed to list images: {}", e))?;
+        images
+            .iter()
+            .any(|candidate| candidate.parent_id == image)
+            .then_some("other images are built on top of it")
+    };
+
+    if let Some(reason) = keep_reason {
+        return Ok(PurgeResult {
+            container_id: container_id.clone(),
+            stopped: running,
+            image_id: Some(image.clone()),
+            image_removed: false,
+            message: format!("{}; image {} kept because {}", removed_message, image, reason),
+        });
+    }
+
+    // `force` is needed when the image carries several tags
+    let removed = docker
+        .remove_image(&image, Some(bollard::image::RemoveImageOptions {
+            force: force_image,
+            noprune: false,
+        }), None)
+        .await
+        .map_err(|e| format!("Container {} removed, but failed to remove image {}: {}", container_id, image, e))?;
+
+    Ok(PurgeResult {
+        container_id: container_id.clone(),
+        stopped: running,
+        image_id: Some(image.clone()),
+        image_removed: true,
+        message: format!(
+            "{}, along with image {} ({} references or layers deleted)",
+            removed_message,
+            image,
+            removed.len()
+        ),
+    })
+}
+
+#[tauri::command]
+async fn prune_containers(
+    until: Option<String>,
+    label: Option<String>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ContainerPruneResult, String> {
+    let docker = docker_client.get().await?;
+
+    // `until` accepts durations ("24h") or timestamps, `label` accepts "key" or "key=value"
+    let mut filters: HashMap<String, Vec<String>> = HashMap::new();
+    if let Some(until) = until.filter(|u| !u.trim().is_empty()) {
+        filters.insert("until".to_string(), vec![until]);
+    }
+    if let Some(label) = label.filter(|l| !l.trim().is_empty()) {
+        filters.insert("label".to_string(), vec![label]);
+    }
+
+    let response = docker
+        .prune_containers(Some(bollard::container::PruneContainersOptions { filters }))
+        .await
+        .map_err(|e| format!("Failed to prune containers: {}", e))?;
+
+    Ok(ContainerPruneResult {
+        containers_deleted: response.containers_deleted.unwrap_or_default(),
+        space_reclaimed: response.space_reclaimed.unwrap_or(0).max(0) as u64,
+    })
+}
+
+// How long a `prepare_full_prune` token can be redeemed
+const FULL_PRUNE_TOKEN_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+// Networks Docker creates itself and never prunes
+const BUILTIN_NETWORKS: [&str; 5] = ["bridge", "host", "none", "ingress", "docker_gwbridge"];
+
+// The prune last shown to the user, redeemable once by its token
+#[derive(Default)]
+pub struct PendingPrune(std::sync::Mutex<Option<(String, std::time::Instant, PruneSummary)>>);
+
+fn prune_token() -> String {
+    use sha2::{Digest, Sha256};
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let digest = Sha256::digest(format!("{}-{}", nanos, std::process::id()).as_bytes());
+    digest.iter().take(8).map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// Everything `docker system prune -a --volumes` would remove: stopped containers, and the
+// images, networks and volumes no remaining (running) container uses
+async fn compute_full_prune(docker: &Docker) -> Result<PruneSummary, String> {
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            size: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let mut summary = PruneSummary::default();
+    let mut kept_images = std::collections::HashSet::new();
+    let mut kept_networks = std::collections::HashSet::new();
+    let mut kept_volumes = std::collections::HashSet::new();
+
+    for container in containers {
+        let state = container.state.clone().unwrap_or_default();
+        if matches!(state.as_str(), "running" | "paused" | "restarting") {
+            kept_images.extend(container.image_id.clone());
+            if let Some(networks) = container.network_settings.and_then(|settings| settings.networks) {
+                kept_networks.extend(networks.into_keys());
+            }
+            kept_volumes.extend(container.mounts.unwrap_or_default().into_iter().filter_map(|mount| mount.name));
+        } else if let Some(id) = container.id {
+            summary.estimated_bytes += container.size_rw.unwrap_or(0).max(0) as u64;
+            summary.containers.push(id);
+        }
+    }
+
+    let images = docker
+        .list_images(Some(ListImagesOptions::<String> {
+            all: false,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list images: {}", e))?;
+    for image in images.into_iter().filter(|image| !kept_images.contains(&image.id)) {
+        summary.estimated_bytes += image.size.max(0) as u64;
+        summary.images.push(image.id);
+    }
+
+    let networks = docker
+        .list_networks(None::<ListNetworksOptions<String>>)
+        .await
+        .map_err(|e| format!("Failed to list networks: {}", e))?;
+    for network in networks {
+        let name = network.name.unwrap_or_default();
+        if BUILTIN_NETWORKS.contains(&name.as_str()) || kept_networks.contains(&name) {
+            continue;
+        }
+        summary.networks.extend(network.id);
+    }
+
+    summary.volumes = fetch_volumes(docker, None)
+        .await?
+        .into_iter()
+        .map(|volume| volume.name)
+        .filter(|name| !kept_volumes.contains(name))
+        .collect();
+
+    Ok(summary)
+}
+
+// First step of the full cleanup: show what would go and hand out a short-lived token
+#[tauri::command]
+async fn prepare_full_prune(
+    pending: tauri::State<'_, PendingPrune>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<PreparedPrune, String> {
+    let docker = docker_client.get().await?;
+
+    let summary = compute_full_prune(&docker).await?;
+    let token = prune_token();
+
+    if let Ok(mut pending) = pending.0.lock() {
+        *pending = Some((token.clone(), std::time::Instant::now(), summary.clone()));
+    }
+
+    Ok(PreparedPrune { token, summary })
+}
+
+// Second step: removes exactly what `prepare_full_prune` showed, nothing created since
+#[tauri::command]
+async fn execute_full_prune(
+    token: String,
+    pending: tauri::State<'_, PendingPrune>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<BulkResult>, String> {
+    // Tokens are single use, whether or not they turn out to be valid
+    let prepared = pending.0.lock().ok().and_then(|mut pending| pending.take());
+    let summary = match prepared {
+        Some((expected, issued_at, summary)) if expected == token => {
+            if issued_at.elapsed() > FULL_PRUNE_TOKEN_TTL {
+                return Err("Prune confirmation expired. Review the cleanup again before confirming".to_string());
+            }
+            summary
+        }
+        _ => return Err("Invalid prune confirmation token".to_string()),
+    };
+
+    let docker = docker_client.get().await?;
+    let mut results = Vec::new();
+
+    for id in summary.containers {
+        let outcome = docker.remove_container(&id, None).await;
+        results.push(BulkResult {
+            success: outcome.is_ok(),
+            message: match outcome {
+                Ok(_) => "Container removed".to_string(),
+                Err(e) => format!("Failed to remove container: {}", e),
+            },
+            id,
+        });
+    }
+
+    // Re-check usage: a container may have been created from one of these images meanwhile
+    let in_use = images_in_use(&docker).await?;
+    for id in summary.images {
+        if in_use.contains(&id) {
+            results.push(BulkResult {
+                id,
+                success: false,
+                message: "Skipped: image is now used by a container".to_string(),
+            });
+            continue;
+        }
+        // `force` untags images that carry several tags
+        let outcome = docker
+            .remove_image(&id, Some(bollard::image::RemoveImageOptions { force: true, noprune: false }), None)
+            .await;
+        results.push(BulkResult {
+            success: outcome.is_ok(),
+            message: match outcome {
+                Ok(_) => "Image removed".to_string(),
+                Err(e) => format!("Failed to remove image: {}", e),
+            },
+            id,
+        });
+    }
+
+    for id in summary.networks {
+        let outcome = docker.remove_network(&id).await;
+        results.push(BulkResult {
+            success: outcome.is_ok(),
+            message: match outcome {
+                Ok(_) => "Network removed".to_string(),
+                Err(e) => format!("Failed to remove network: {}", e),
+            },
+            id,
+        });
+    }
+
+    for name in summary.volumes {
+        let outcome = docker.remove_volume(&name, None).await;
+        results.push(BulkResult {
+            success: outcome.is_ok(),
+            message: match outcome {
+                Ok(_) => "Volume removed".to_string(),
+                Err(e) => format!("Failed to remove volume: {}", e),
+            },
+            id: name,
+        });
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+async fn stopped_containers_disk(
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<StoppedContainerDisk>, String> {
+    let docker = docker_client.get().await?;
+
+    // `size: true` makes the daemon compute each container's writable layer size
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            size: true,
+            filters: HashMap::from([("status".to_string(), vec!["exited".to_string(), "created".to_string(), "dead".to_string()])]),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let mut stopped = Vec::with_capacity(containers.len());
+    for container in containers {
+        let size_rw = container.size_rw.unwrap_or(0).max(0) as u64;
+        let info = container_info_from_summary(container);
+
+        let finished_at = docker
+            .inspect_container(&info.id, None)
+            .await
+            .ok()
+            .and_then(|inspect| inspect.state)
+            .and_then(|state| state.finished_at);
+
+        stopped.push(StoppedContainerDisk {
+            id: info.id,
+            name: info.name,
+            size_rw,
+            finished_at,
+        });
+    }
+
+    stopped.sort_by(|a, b| b.size_rw.cmp(&a.size_rw));
+
+    Ok(stopped)
+}
+
+#[tauri::command]
+async fn pause_container(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    docker
+        .pause_container(&container_id)
+        .await
+        .map_err(|e| format!("Failed to pause container: {}", e))?;
+
+    Ok(format!("Container {} paused successfully", container_id))
+}
+
+#[tauri::command]
+async fn unpause_container(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+
+    docker
+        .unpause_container(&container_id)
+        .await
+        .map_err(|e| format!("Failed to unpause container: {}", e))?;
+
+    Ok(format!("Container {} unpaused successfully", container_id))
+}
+
+// Signal names Docker accepts, without the SIG prefix
+const SIGNAL_NAMES: [&str; 31] = [
+    "HUP", "INT", "QUIT", "ILL", "TRAP", "ABRT", "BUS", "FPE", "KILL", "USR1", "SEGV", "USR2", "PIPE", "ALRM", "TERM",
+    "STKFLT", "CHLD", "CONT", "STOP", "TSTP", "TTIN", "TTOU", "URG", "XCPU", "XFSZ", "VTALRM", "PROF", "WINCH", "IO",
+    "PWR", "SYS",
+];
+
+// Accepts "HUP", "SIGHUP", "sighup" or a signal number and returns Docker's canonical form
+fn normalize_signal(signal: &str) -> Result<String, String> {
+    let trimmed = signal.trim();
+    if let Ok(number) = trimmed.parse::<u32>() {
+        // Real-time signals (34-64) have no fixed name, so numbers pass through as-is
+        return if (1..=64).contains(&number) {
+            Ok(number.to_string())
+        } else {
+            Err(format!("Invalid signal number {}", number))
+        };
+    }
+
+    let upper = trimmed.to_uppercase();
+    let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+    if SIGNAL_NAMES.contains(&name) {
+        Ok(format!("SIG{}", name))
+    } else {
+        Err(format!("Unknown signal '{}'", signal))
+    }
+}
+
+// Delivers any signal through the kill endpoint. SIGKILL (and SIGTERM/SIGINT for most
+// processes) end the container; others such as SIGHUP or SIGUSR1 are just delivered to PID 1.
+#[tauri::command]
+async fn signal_container(
+    container_id: String,
+    signal: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let signal = normalize_signal(&signal)?;
+    let docker = docker_client.get().await?;
+
+    docker
+        .kill_container(&container_id, Some(bollard::container::KillContainerOptions { signal: signal.as_str() }))
+        .await
+        .map_err(|e| format!("Failed to send {} to container: {}", signal, e))?;
+
+    Ok(format!("Sent {} to container {}", signal, container_id))
+}
+
+// How long prefetched container stats are served before sampling again
+const STATS_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[derive(Default, Clone)]
+pub struct StatsCache(std::sync::Arc<std::sync::Mutex<HashMap<String, (std::time::Instant, ContainerStats)>>>);
+
+impl StatsCache {
+    fn get_fresh(&self, container_id: &str) -> Option<ContainerStats> {
+        let entries = self.0.lock().ok()?;
+        entries
+            .get(container_id)
+            .filter(|(sampled_at, _)| sampled_at.elapsed() < STATS_CACHE_TTL)
+            .map(|(_, stats)| stats.clone())
+    }
+
+    fn insert(&self, stats: ContainerStats) {
+        if let Ok(mut entries) = self.0.lock() {
+            entries.retain(|_, (sampled_at, _)| sampled_at.elapsed() < STATS_CACHE_TTL);
+            entries.insert(stats.id.clone(), (std::time::Instant::now(), stats));
+        }
+    }
+}
+
+fn prefetch_container_stats(docker: Docker, container_ids: Vec<String>, cache: StatsCache) {
+    if container_ids.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let stats_futures = container_ids
+            .iter()
+            .filter(|id| cache.get_fresh(id).is_none())
+            .map(|id| collect_container_stats(&docker, id, false, false));
+
+        for stats in futures_util::future::join_all(stats_futures).await.into_iter().flatten() {
+            cache.insert(stats);
+        }
+    });
+}
+
+async fn project_containers(docker: &Docker, project: &str) -> Result<Vec<ContainerInfo>, String> {
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            filters: HashMap::from([(
+                "label".to_string(),
+                vec![format!("com.docker.compose.project={}", project)],
+            )]),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    Ok(containers.into_iter().map(container_info_from_summary).collect())
+}
+
+// Pause or unpause every container of a Compose project concurrently. Containers that
+// aren't in `required_state` are skipped and reported as such.
+async fn set_project_paused(docker: &Docker, project: String, pause: bool) -> Result<Vec<BulkResult>, String> {
+    let containers = project_containers(docker, &project).await?;
+    if containers.is_empty() {
+        return Err(format!("No containers found for project '{}'", project));
+    }
+
+    let required_state = if pause { "running" } else { "paused" };
+    let action = if pause { "pause" } else { "unpause" };
+
+    let results = futures_util::future::join_all(containers.into_iter().map(|container| {
+        let docker = &docker;
+        async move {
+            if container.state != required_state {
+                return BulkResult {
+                    id: container.id,
+                    success: false,
+                    message: format!("Skipped {}: container is {}", container.name, container.state),
+                };
+            }
+
+            let result = if pause {
+                docker.pause_container(&container.id).await
+            } else {
+                docker.unpause_container(&container.id).await
+            };
+
+            match result {
+                Ok(_) => BulkResult {
+                    id: container.id,
+                    success: true,
+                    message: format!("Container {} {}d", container.name, action),
+                },
+                Err(e) => BulkResult {
+                    id: container.id,
+                    success: false,
+                    message: format!("Failed to {} container {}: {}", action, container.name, e),
+                },
+            }
+        }
+    }))
+    .await;
+
+    Ok(results)
+}
+
+// Containers stopped at once by `stop_all_running`, so a large host isn't hit with every stop together
+const STOP_ALL_CONCURRENCY: usize = 8;
+
+// Stop every running container. Swarm tasks are skipped because the orchestrator would
+// just start replacements; they are listed in the result as skipped.
+#[tauri::command]
+async fn stop_all_running(
+    timeout_secs: Option<i64>,
+    app_handle: tauri::AppHandle,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<BulkResult>, String> {
+    let docker = docker_client.get().await?;
+
+    let running: Vec<ContainerInfo> = fetch_containers(&docker)
+        .await?
+        .into_iter()
+        .filter(|container| container.state == "running")
+        .collect();
+
+    let (swarm_managed, stoppable): (Vec<ContainerInfo>, Vec<ContainerInfo>) = running
+        .into_iter()
+        .partition(|container| container.labels.contains_key("com.docker.swarm.service.id"));
+
+    let mut results: Vec<BulkResult> = swarm_managed
+        .into_iter()
+        .map(|container| BulkResult {
+            id: container.id,
+            success: false,
+            message: format!("Skipped {}: managed by a Swarm service and would be rescheduled", container.name),
+        })
+        .collect();
+
+    let mut stops = futures_util::stream::iter(stoppable.into_iter().map(|container| {
+        let docker = &docker;
+        async move {
+            let stop_options = timeout_secs.map(|t| bollard::container::StopContainerOptions { t });
+            match docker.stop_container(&container.id, stop_options).await {
+                Ok(_) => BulkResult {
+                    id: container.id,
+                    success: true,
+                    message: format!("Container {} stopped", container.name),
+                },
+                Err(e) => BulkResult {
+                    id: container.id,
+                    success: false,
+                    message: format!("Failed to stop container {}: {}", container.name, e),
+                },
+            }
+        }
+    }))
+    .buffer_unordered(STOP_ALL_CONCURRENCY);
+
+    while let Some(result) = stops.next().await {
+        let _ = app_handle.emit("stop-all-progress", result.clone());
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+async fn pause_project(project: String, docker_client: tauri::State<'_, DockerClient>) -> Result<Vec<BulkResult>, String> {
+    let docker = docker_client.get().await?;
+    set_project_paused(&docker, project, true).await
+}
+
+#[tauri::command]
+async fn unpause_project(
+    project: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<BulkResult>, String> {
+    let docker = docker_client.get().await?;
+    set_project_paused(&docker, project, false).await
+}
+
+#[tauri::command]
+async fn get_container_stats(
+    container_id: String,
+    per_cpu: Option<bool>,
+    per_interface: Option<bool>,
+    stats_cache: tauri::State<'_, StatsCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ContainerStats, String> {
+    let per_cpu = per_cpu.unwrap_or(false);
+    let per_interface = per_interface.unwrap_or(false);
+
+    // The cache only holds aggregate samples
+    let cacheable = !per_cpu && !per_interface;
+    if cacheable {
+        if let Some(stats) = stats_cache.get_fresh(&container_id) {
+            return Ok(stats);
+        }
+    }
+
+    let docker = docker_client.get().await?;
+
+    let stats = collect_container_stats(&docker, &container_id, per_cpu, per_interface).await?;
+    if cacheable {
+        stats_cache.insert(stats.clone());
+    }
+
+    Ok(stats)
+}
+
+// Samples a container's stats for `duration_secs` in the background, appending a CSV row every
+// `interval_secs` so a partial capture survives an early stop
+#[tauri::command]
+async fn export_stats_timeseries(
+    container_id: String,
+    duration_secs: u64,
+    interval_secs: u64,
+    output_path: String,
+    app_handle: tauri::AppHandle,
+    cancellation: tauri::State<'_, StreamCancellation>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    use tokio::io::AsyncWriteExt;
+
+    if interval_secs == 0 {
+        return Err("Sampling interval must be at least one second".to_string());
+    }
+    if duration_secs < interval_secs {
+        return Err("Duration must be at least one sampling interval".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+    let token = cancellation.token();
+
+    let mut file = tokio::fs::File::create(&output_path)
+        .await
+        .map_err(|e| format!("Failed to create output file '{}': {}", output_path, e))?;
+    file.write_all(b"timestamp,cpu_percentage,memory_bytes,memory_percentage,network_rx,network_tx,block_read,block_write\n")
+        .await
+        .map_err(|e| format!("Failed to write stats export: {}", e))?;
+
+    let event_id = event_safe(&container_id);
+    let sample_count = duration_secs / interval_secs;
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+        let mut written = 0u64;
+
+        let outcome: Result<(), String> = async {
+            while written < sample_count {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+
+                let stats = collect_container_stats(&docker, &container_id, false, false).await?;
+                let row = format!(
+                    "{},{:.2},{},{:.2},{},{},{},{}\n",
+                    unix_timestamp(),
+                    stats.cpu_percentage,
+                    stats.memory_usage,
+                    stats.memory_percentage,
+                    stats.network_rx,
+                    stats.network_tx,
+                    stats.block_read,
+                    stats.block_write,
+                );
+                file.write_all(row.as_bytes())
+                    .await
+                    .map_err(|e| format!("Failed to write stats export: {}", e))?;
+                written += 1;
+            }
+
+            file.flush().await.map_err(|e| format!("Failed to flush stats export: {}", e))
+        }
+        .await;
+
+        match outcome {
+            Ok(()) => {
+                let _ = app_handle.emit(
+                    &format!("stats-export-complete-{}", event_id),
+                    format!("Wrote {} samples to {}", written, output_path),
+                );
+            }
+            Err(e) => {
+                let _ = app_handle.emit(
+                    &format!("stats-export-error-{}", event_id),
+                    format!("{} (after {} samples)", e, written),
+                );
+            }
+        }
+    });
+
+    Ok(format!("Sampling stats for {} every {}s over {}s", container_id, interval_secs, duration_secs))
+}
+
+#[tauri::command]
+async fn get_all_container_stats(
+    stats_cache: tauri::State<'_, StatsCache>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ContainerStats>, String> {
+    let docker = docker_client.get().await?;
+
+    let running = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            filters: HashMap::from([("status".to_string(), vec!["running".to_string()])]),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let container_ids: Vec<String> = running.into_iter().filter_map(|container| container.id).collect();
+
+    let mut all_stats = Vec::with_capacity(container_ids.len());
+    let mut missing = Vec::new();
+    for container_id in &container_ids {
+        match stats_cache.get_fresh(container_id) {
+            Some(stats) => all_stats.push(stats),
+            None => missing.push(container_id.as_str()),
+        }
+    }
+
+    let fetched = futures_util::future::join_all(
+        missing.into_iter().map(|id| collect_container_stats(&docker, id, false, false)),
+    )
+    .await;
+
+    for stats in fetched.into_iter().flatten() {
+        stats_cache.insert(stats.clone());
+        all_stats.push(stats);
+    }
+
+    Ok(all_stats)
+}
+
+// Standard `docker stats` formula: share of host CPU time used between the sample and
+// its predecessor, scaled by online CPUs (so 200% means two cores fully busy)
+fn aggregate_cpu_percentage(stats: &bollard::container::Stats) -> f64 {
+    let cpu_stats = &stats.cpu_stats;
+    let precpu_stats = &stats.precpu_stats;
+
+    let cpu_delta = cpu_stats.cpu_usage.total_usage.saturating_sub(precpu_stats.cpu_usage.total_usage);
+    let system_delta = cpu_stats.system_cpu_usage.unwrap_or(0).saturating_sub(precpu_stats.system_cpu_usage.unwrap_or(0));
+    let online_cpus = cpu_stats.online_cpus.unwrap_or(1) as f64;
+
+    if system_delta > 0 && cpu_delta > 0 {
+        (cpu_delta as f64 / system_delta as f64) * online_cpus * 100.0
+    } else {
+        0.0
+    }
+}
+
+// Total memory of the Docker host (the VM on Docker Desktop), looked up once
+static DAEMON_MEMORY_TOTAL: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+async fn daemon_memory_total(docker: &Docker) -> u64 {
+    use std::sync::atomic::Ordering;
+
+    let cached = DAEMON_MEMORY_TOTAL.load(Ordering::Relaxed);
+    if cached > 0 {
+        return cached;
+    }
+
+    // Fall back to u64::MAX so a failed lookup never labels a real limit as unlimited
+    match docker.info().await.ok().and_then(|info| info.mem_total) {
+        Some(total) if total > 0 => {
+            DAEMON_MEMORY_TOTAL.store(total as u64, Ordering::Relaxed);
+            total as u64
+        }
+        _ => u64::MAX,
+    }
+}
+
+// Binary units, e.g. 268435456 -> "256 MiB", 1610612736 -> "1.5 GiB"
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    let formatted = format!("{:.1}", value);
+    format!("{} {}", formatted.trim_end_matches(".0"), UNITS[unit])
+}
+
+async fn collect_container_stats(
+    docker: &Docker,
+    container_id: &str,
+    per_cpu: bool,
+    per_interface: bool,
+) -> Result<ContainerStats, String> {
+    // Get container info first to get the name
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            filters: {
+                let mut filters = HashMap::new();
+                filters.insert("id".to_string(), vec![container_id.to_string()]);
+                filters
+            },
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| format!("Failed to get container info: {}", e))?;
+
+    let container_name = containers
+        .first()
+        .and_then(|c| c.names.as_ref())
+        .and_then(|names| names.first())
+        .map(|name| name.trim_start_matches('/').to_string())
+        .unwrap_or_else(|| format!("container-{}", &container_id[..8]));
+
+    // A one-shot sample carries no previous CPU reading, so take two and diff them
+    let first = sample_container_stats(docker, container_id).await?;
+    tokio::time::sleep(STATS_SAMPLE_GAP).await;
+    let mut stats = sample_container_stats(docker, container_id).await?;
+    stats.precpu_stats = first.cpu_stats;
+
+    Ok(container_stats_from_sample(docker, container_id, container_name, &stats, per_cpu, per_interface).await)
+}
+
+// Gap between the two samples `collect_container_stats` diffs for CPU usage
+const STATS_SAMPLE_GAP: std::time::Duration = std::time::Duration::from_millis(300);
+
+async fn sample_container_stats(docker: &Docker, container_id: &str) -> Result<bollard::container::Stats, String> {
+    let mut stats_stream = docker.stats(container_id, Some(bollard::container::StatsOptions {
+        stream: false,
+        one_shot: true,
+    }));
+
+    match stats_stream.next().await {
+        Some(Ok(stats)) => Ok(stats),
+        Some(Err(e)) => Err(format!("Failed to get container stats: {}", e)),
+        None => Err("Failed to get container stats".to_string()),
+    }
+}
+
+// Build the frontend payload from a stats sample whose `precpu_stats` holds the previous reading
+async fn container_stats_from_sample(
+    docker: &Docker,
+    container_id: &str,
+    container_name: String,
+    stats: &bollard::container::Stats,
+    per_cpu: bool,
+    per_interface: bool,
+) -> ContainerStats {
+    // Calculate CPU percentage - simplified approach
+    let online_cpus = stats.cpu_stats.online_cpus.unwrap_or(1) as f64;
+    let aggregate_percentage = aggregate_cpu_percentage(stats);
+
+    let (cpu_percentage, cpu_mode) = if per_cpu {
+        (aggregate_percentage / online_cpus.max(1.0), "per_cpu")
+    } else {
+        (aggregate_percentage, "aggregate")
+    };
+
+    // Memory stats
+    let memory_usage = stats.memory_stats.usage.unwrap_or(0);
+    let memory_limit = stats.memory_stats.limit.unwrap_or(0);
+    let memory_percentage = if memory_limit > 0 {
+        (memory_usage as f64 / memory_limit as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    // Network stats
+    let (network_rx, network_tx) = if let Some(networks) = &stats.networks {
+        let mut rx_bytes = 0u64;
+        let mut tx_bytes = 0u64;
+        
+        for (_, network) in networks {
+            rx_bytes += network.rx_bytes;
+            tx_bytes += network.tx_bytes;
+        }
+        
+        (rx_bytes, tx_bytes)
+    } else {
+        (0, 0)
+    };
+
+    let network_interfaces = per_interface.then(|| {
+        stats
+            .networks
+            .iter()
+            .flatten()
+            .map(|(interface, network)| {
+                (interface.clone(), InterfaceTraffic {
+                    rx_bytes: network.rx_bytes,
+                    tx_bytes: network.tx_bytes,
+                })
+            })
+            .collect()
+    });
+
+    // Block I/O stats
+    let (block_read, block_write) = if let Some(io_service_bytes_recursive) = &stats.blkio_stats.io_service_bytes_recursive {
+        let mut read_bytes = 0u64;
+        let mut write_bytes = 0u64;
+        
+        for io_stat in io_service_bytes_recursive {
+            match io_stat.op.as_str() {
+                "read" | "Read" => read_bytes += io_stat.value,
+                "write" | "Write" => write_bytes += io_stat.value,
+                _ => {}
+            }
+        }
+        
+        (read_bytes, write_bytes)
+    } else {
+        (0, 0)
+    };
+
+    // Without a limit Docker reports the daemon host's total memory
+    let memory_limit_human = if memory_limit == 0 || memory_limit >= daemon_memory_total(docker).await {
+        "unlimited".to_string()
+    } else {
+        format_bytes(memory_limit)
+    };
+
+    ContainerStats {
+        id: container_id.to_string(),
+        name: container_name,
+        cpu_percentage,
+        cpu_mode: cpu_mode.to_string(),
+        memory_usage,
+        memory_limit,
+        memory_percentage,
+        memory_usage_human: format_bytes(memory_usage),
+        memory_limit_human,
+        network_rx,
+        network_tx,
+        network_interfaces,
+        block_read,
+        block_write,
+    }
+}
+
+// Abort handles of running log streams keyed by container id. Each entry carries a
+// sequence number so a finished stream only removes its own entry, not a newer one.
+#[derive(Default)]
+pub struct LogStreams(std::sync::Mutex<HashMap<String, (u64, tokio::task::AbortHandle)>>);
+
+static LOG_STREAM_SEQUENCE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+impl LogStreams {
+    fn remove(&self, container_id: &str, sequence: u64) {
+        if let Ok(mut streams) = self.0.lock() {
+            if streams.get(container_id).is_some_and(|(current, _)| *current == sequence) {
+                streams.remove(container_id);
+            }
+        }
+    }
+}
+
+// Per-container tokens for running stats streams, children of the global stream token. Like
+// `LogStreams`, each entry carries a sequence number so a finished stream only removes its own entry.
+#[derive(Default)]
+pub struct StatsStreams(std::sync::Mutex<HashMap<String, (u64, CancellationToken)>>);
+
+static STATS_STREAM_SEQUENCE: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+impl StatsStreams {
+    fn remove(&self, container_id: &str, sequence: u64) {
+        if let Ok(mut streams) = self.0.lock() {
+            if streams.get(container_id).is_some_and(|(current, _)| *current == sequence) {
+                streams.remove(container_id);
+            }
+        }
+    }
+}
+
+// Emits a `ContainerStats` on `stats-stream-{id}` for every sample the daemon streams (about
+// once a second). Streamed samples carry the previous reading, so CPU usage is a real delta.
+#[tauri::command]
+async fn start_stats_stream(
+    container_id: String,
+    per_cpu: Option<bool>,
+    per_interface: Option<bool>,
+    app_handle: tauri::AppHandle,
+    cancellation: tauri::State<'_, StreamCancellation>,
+    streams: tauri::State<'_, StatsStreams>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+    let per_cpu = per_cpu.unwrap_or(false);
+    let per_interface = per_interface.unwrap_or(false);
+
+    let token = cancellation.token().child_token();
+    let sequence = STATS_STREAM_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    if let Ok(mut streams) = streams.0.lock() {
+        // Restarting a stream replaces the previous one for the same container
+        if let Some((_, previous)) = streams.insert(container_id.clone(), (sequence, token.clone())) {
+            previous.cancel();
+        }
+    }
+
+    let event_id = event_safe(&container_id);
+
+    tokio::spawn(async move {
+        let mut stats_stream = docker.stats(&container_id, Some(bollard::container::StatsOptions {
+            stream: true,
+            one_shot: false,
+        }));
+
+        while let Some(stats_result) = tokio::select! {
+            _ = token.cancelled() => None,
+            next = stats_stream.next() => next,
+        } {
+            match stats_result {
+                // The first streamed sample has no previous reading to diff against
+                Ok(stats) if stats.precpu_stats.system_cpu_usage.is_none() => continue,
+                Ok(stats) => {
+                    let name = stats.name.trim_start_matches('/').to_string();
+                    let payload = container_stats_from_sample(&docker, &container_id, name, &stats, per_cpu, per_interface).await;
+                    if app_handle.emit(&format!("stats-stream-{}", event_id), payload).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let _ = app_handle.emit(&format!("stats-stream-error-{}", event_id), format!("Stats stream error: {}", e));
+                    break;
+                }
+            }
+        }
+
+        app_handle.state::<StatsStreams>().remove(&container_id, sequence);
+
+        let _ = app_handle.emit(&format!("stats-stream-ended-{}", event_id), "Stats stream ended");
+    });
+
+    Ok("Stats stream started".to_string())
+}
+
+#[tauri::command]
+async fn stop_stats_stream(container_id: String, streams: tauri::State<'_, StatsStreams>) -> Result<String, String> {
+    let stream = streams.0.lock().ok().and_then(|mut streams| streams.remove(&container_id));
+
+    match stream {
+        Some((_, token)) => {
+            token.cancel();
+            Ok("Stats stream stopped".to_string())
+        }
+        None => Ok("No stats stream running for this container".to_string()),
+    }
+}
+
+#[tauri::command]
+async fn detailed_resource_stats(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<DetailedResourceStats, String> {
+    let docker = docker_client.get().await?;
+
+    let mut stats_stream = docker.stats(&container_id, Some(bollard::container::StatsOptions {
+        stream: false,
+        one_shot: true,
+    }));
+
+    let stats = match stats_stream.next().await {
+        Some(Ok(stats)) => stats,
+        Some(Err(e)) => return Err(format!("Failed to get container stats: {}", e)),
+        None => return Err("Failed to get container stats".to_string()),
+    };
+
+    let (memory_cache, memory_rss) = match &stats.memory_stats.stats {
+        Some(bollard::container::MemoryStatsStats::V1(v1)) => (Some(v1.cache), Some(v1.rss)),
+        Some(bollard::container::MemoryStatsStats::V2(v2)) => (Some(v2.file), Some(v2.anon)),
+        None => (None, None),
+    };
+
+    let cpu_usage = &stats.cpu_stats.cpu_usage;
+    let throttling = &stats.cpu_stats.throttling_data;
+
+    Ok(DetailedResourceStats {
+        id: container_id,
+        cpu_total_usage: cpu_usage.total_usage,
+        cpu_usage_in_kernelmode: cpu_usage.usage_in_kernelmode,
+        cpu_usage_in_usermode: cpu_usage.usage_in_usermode,
+        per_cpu_usage: cpu_usage.percpu_usage.clone().unwrap_or_default(),
+        online_cpus: stats.cpu_stats.online_cpus,
+        throttling_periods: throttling.periods,
+        throttled_periods: throttling.throttled_periods,
+        throttled_time: throttling.throttled_time,
+        memory_usage: stats.memory_stats.usage,
+        memory_max_usage: stats.memory_stats.max_usage,
+        memory_limit: stats.memory_stats.limit,
+        memory_cache,
+        memory_rss,
+        pids_current: stats.pids_stats.current,
+    })
+}
+
+// Samples taken by `recommend_limits`; the daemon emits one stats sample per second
+const RECOMMENDATION_SAMPLES: usize = 10;
+
+// Headroom applied on top of observed peaks when suggesting limits
+const RECOMMENDATION_HEADROOM: f64 = 1.3;
+
+// Smallest memory limit the daemon accepts
+const MIN_MEMORY_LIMIT: u64 = 6 * 1024 * 1024;
+
+#[tauri::command]
+async fn recommend_limits(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ResourceRecommendation, String> {
+    let docker = docker_client.get().await?;
+
+    let mut stats_stream = docker.stats(&container_id, Some(bollard::container::StatsOptions {
+        stream: true,
+        one_shot: false,
+    }));
+
+    let mut peak_memory: u64 = 0;
+    let mut peak_cpu_percentage: f64 = 0.0;
+    let mut samples = 0;
+
+    // The first sample has no predecessor to diff CPU against, so it only counts for memory
+    while let Some(result) = stats_stream.next().await {
+        let stats = result.map_err(|e| format!("Failed to read container stats: {}", e))?;
+        peak_memory = peak_memory.max(stats.memory_stats.usage.unwrap_or(0));
+        if samples > 0 {
+            peak_cpu_percentage = peak_cpu_percentage.max(aggregate_cpu_percentage(&stats));
+        }
+
+        samples += 1;
+        if samples >= RECOMMENDATION_SAMPLES {
+            break;
+        }
+    }
+
+    if samples < 2 {
+        return Err("Not enough stats samples to make a recommendation; is the container running?".to_string());
+    }
+
+    let suggested_memory = ((peak_memory as f64 * RECOMMENDATION_HEADROOM) as u64).max(MIN_MEMORY_LIMIT);
+    // Round up to the nearest 0.05 CPU, never suggesting less than a tenth of a core
+    let suggested_cpus = ((peak_cpu_percentage / 100.0 * RECOMMENDATION_HEADROOM * 20.0).ceil() / 20.0).max(0.1);
+
+    Ok(ResourceRecommendation {
+        suggested_memory,
+        suggested_cpus,
+        basis: format!(
+            "Heuristic only: peak usage over a {}-sample (~{}s) observation window was {:.1} MiB memory and {:.2} CPUs; suggestions add {:.0}% headroom. Observe under realistic load before applying.",
+            samples,
+            samples,
+            peak_memory as f64 / 1_048_576.0,
+            peak_cpu_percentage / 100.0,
+            (RECOMMENDATION_HEADROOM - 1.0) * 100.0
+        ),
+    })
+}
+
+// Number of containers included in the dashboard's top-usage widget
+const DASHBOARD_TOP_CONTAINERS: usize = 5;
+
+// Default cadence of backend-driven polling such as the dashboard feed
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 2;
+
+// Bounds accepted by `set_poll_interval`
+const POLL_INTERVAL_RANGE: std::ops::RangeInclusive<u64> = 1..=300;
+
+// Runtime settings shared by backend pollers
+pub struct Settings {
+    poll_interval_secs: std::sync::atomic::AtomicU64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: std::sync::atomic::AtomicU64::new(DEFAULT_POLL_INTERVAL_SECS),
+        }
+    }
+}
+
+impl Settings {
+    fn poll_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.poll_interval_secs.load(std::sync::atomic::Ordering::Relaxed))
+    }
+}
+
+#[tauri::command]
+async fn set_poll_interval(seconds: u64, settings: tauri::State<'_, Settings>) -> Result<String, String> {
+    if !POLL_INTERVAL_RANGE.contains(&seconds) {
+        return Err(format!(
+            "Poll interval must be between {} and {} seconds",
+            POLL_INTERVAL_RANGE.start(),
+            POLL_INTERVAL_RANGE.end()
+        ));
+    }
+
+    settings.poll_interval_secs.store(seconds, std::sync::atomic::Ordering::Relaxed);
+    Ok(format!("Poll interval set to {} seconds", seconds))
+}
+
+#[tauri::command]
+async fn get_poll_interval(settings: tauri::State<'_, Settings>) -> Result<u64, String> {
+    Ok(settings.poll_interval().as_secs())
+}
+
+// One shared token that every background stream selects on, so all of them can be shut
+// down together (window close, daemon disconnect, or an explicit `cancel_all_streams`)
+#[derive(Default)]
+pub struct StreamCancellation(std::sync::Mutex<CancellationToken>);
+
+impl StreamCancellation {
+    fn token(&self) -> CancellationToken {
+        self.0.lock().map(|token| token.clone()).unwrap_or_default()
+    }
+
+    // Cancel every running stream and arm a fresh token for streams started afterwards
+    fn cancel_all(&self) {
+        if let Ok(mut token) = self.0.lock() {
+            token.cancel();
+            *token = CancellationToken::new();
+        }
+    }
+}
+
+#[tauri::command]
+async fn cancel_all_streams(cancellation: tauri::State<'_, StreamCancellation>) -> Result<String, String> {
+    cancellation.cancel_all();
+    Ok("All streams cancelled".to_string())
+}
+
+// Number of daemon events kept for newly opened event panels
+const EVENT_HISTORY_CAPACITY: usize = 500;
+
+// Delay before re-subscribing after the daemon's event stream drops
+const EVENT_RESUBSCRIBE_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[derive(Default, Clone)]
+pub struct EventHistory(std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<DockerEvent>>>);
+
+impl EventHistory {
+    fn push(&self, event: DockerEvent) {
+        if let Ok(mut events) = self.0.lock() {
+            if events.len() >= EVENT_HISTORY_CAPACITY {
+                events.pop_front();
+            }
+            events.push_back(event);
+        }
+    }
+}
+
+fn docker_event_from_message(message: bollard::models::EventMessage) -> DockerEvent {
+    let actor = message.actor.unwrap_or_default();
+
+    DockerEvent {
+        r#type: message.typ.map(|t| t.to_string()).unwrap_or_else(|| "unknown".to_string()),
+        action: message.action.unwrap_or_default(),
+        actor_id: actor.id.unwrap_or_default(),
+        attributes: actor.attributes.unwrap_or_default(),
+        time: message.time.unwrap_or(0),
+    }
+}
+
+// Records daemon events into the history buffer for the lifetime of the app, re-subscribing
+// whenever the stream ends (daemon restart) or is cancelled
+async fn record_docker_events(app_handle: tauri::AppHandle) {
+    let history = app_handle.state::<EventHistory>().inner().clone();
+
+    loop {
+        let token = app_handle.state::<StreamCancellation>().token();
+
+        if let Ok(docker) = app_handle.state::<DockerClient>().get().await {
+            let mut events = docker.events(None::<bollard::system::EventsOptions<String>>);
+
+            while let Some(result) = tokio::select! {
+                _ = token.cancelled() => None,
+                next = events.next() => next,
+            } {
+                match result {
+                    Ok(message) => history.push(docker_event_from_message(message)),
+                    Err(e) => {
+                        eprintln!("Error reading Docker events: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(EVENT_RESUBSCRIBE_DELAY).await;
+    }
+}
+
+#[tauri::command]
+async fn get_recent_events(
+    limit: usize,
+    type_filter: Option<String>,
+    history: tauri::State<'_, EventHistory>,
+) -> Result<Vec<DockerEvent>, String> {
+    let events = history.0.lock().map_err(|_| "Event history is poisoned".to_string())?;
+
+    // Newest first, so the panel can render from the top
+    Ok(events
+        .iter()
+        .rev()
+        .filter(|event| type_filter.as_deref().map(|t| event.r#type == t).unwrap_or(true))
+        .take(limit)
+        .cloned()
+        .collect())
+}
+
+#[derive(Default)]
+pub struct DockerEventsFeed(std::sync::Mutex<Option<tokio::task::JoinHandle<()>>>);
+
+// Emits every daemon event on `docker-event` so the UI can refresh only the affected list.
+// Re-subscribes when the daemon's stream drops, like the history recorder.
+#[tauri::command]
+async fn start_docker_events(
+    app_handle: tauri::AppHandle,
+    feed: tauri::State<'_, DockerEventsFeed>,
+) -> Result<String, String> {
+    let mut handle = feed.0.lock().map_err(|_| "Docker events feed state is poisoned".to_string())?;
+
+    if handle.as_ref().map(|h| !h.is_finished()).unwrap_or(false) {
+        return Ok("Docker events feed already running".to_string());
+    }
+
+    // One token for the whole feed: cancel_all_streams or a destroyed window ends it, and
+    // the loop only resubscribes after the stream errors or ends
+    let token = app_handle.state::<StreamCancellation>().token();
+
+    *handle = Some(tokio::spawn(async move {
+        while !token.is_cancelled() {
+            if let Ok(docker) = app_handle.state::<DockerClient>().get().await {
+                let mut events = docker.events(None::<bollard::system::EventsOptions<String>>);
+
+                while let Some(result) = tokio::select! {
+                    _ = token.cancelled() => None,
+                    next = events.next() => next,
+                } {
+                    match result {
+                        Ok(message) => {
+                            if let Err(e) = app_handle.emit("docker-event", docker_event_from_message(message)) {
+                                eprintln!("Failed to emit Docker event: {}", e);
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("Error reading Docker events: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = tokio::time::sleep(EVENT_RESUBSCRIBE_DELAY) => {}
+            }
+        }
+    }));
+
+    Ok("Docker events feed started".to_string())
+}
+
+#[tauri::command]
+async fn stop_docker_events(feed: tauri::State<'_, DockerEventsFeed>) -> Result<String, String> {
+    let mut handle = feed.0.lock().map_err(|_| "Docker events feed state is poisoned".to_string())?;
+
+    match handle.take() {
+        Some(task) => {
+            task.abort();
+            Ok("Docker events feed stopped".to_string())
+        }
+        None => Ok("Docker events feed was not running".to_string()),
+    }
+}
+
+#[derive(Default)]
+pub struct DashboardFeedState(std::sync::Mutex<Option<tokio::task::JoinHandle<()>>>);
+
+// One list call plus at most one one-shot sample per container each tick. CPU usage is diffed
+// against the reading from the previous tick, and containers with fresh cached stats are not
+// sampled at all.
+async fn collect_top_container_stats(
+    docker: &Docker,
+    cache: &StatsCache,
+    previous_cpu: &mut HashMap<String, bollard::container::CPUStats>,
+) -> Vec<ContainerStats> {
+    let running = match docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            filters: HashMap::from([("status".to_string(), vec!["running".to_string()])]),
+            ..Default::default()
+        }))
+        .await
+    {
+        Ok(containers) => containers,
+        Err(e) => {
+            eprintln!("Dashboard feed failed to list containers: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut stats = Vec::new();
+    let mut to_sample = Vec::new();
+    for container in &running {
+        let Some(id) = container.id.as_deref() else { continue };
+        match cache.get_fresh(id) {
+            Some(cached) => stats.push(cached),
+            None => {
+                let name = container
+                    .names
+                    .as_ref()
+                    .and_then(|names| names.first())
+                    .map(|name| name.trim_start_matches('/').to_string())
+                    .unwrap_or_else(|| format!("container-{}", id.chars().take(8).collect::<String>()));
+                to_sample.push((id, name));
+            }
+        }
+    }
 
-            NetworkInfo {
-                id: network.id.unwrap_or_default(),
-                name: network.name.unwrap_or_default(),
-                driver: network.driver.unwrap_or_default(),
-                scope: network.scope.unwrap_or_default(),
-                created: network.created,
-                internal: network.internal.unwrap_or(false),
-                attachable: network.attachable.unwrap_or(false),
-                ingress: network.ingress.unwrap_or(false),
-                ipam: NetworkIpam {
-                    driver: network.ipam.as_ref().and_then(|ipam| ipam.driver.clone()),
-                    config: ipam_config,
-                    options: network
-                        .ipam
-                        .as_ref()
-                        .and_then(|ipam| ipam.options.clone())
-                        .unwrap_or_default(),
-                },
-                containers,
-                options: network.options.unwrap_or_default(),
-                labels: network.labels.unwrap_or_default(),
+    let samples = futures_util::future::join_all(
+        to_sample.iter().map(|(id, _)| sample_container_stats(docker, id)),
+    )
+    .await;
+
+    for ((id, name), sample) in to_sample.into_iter().zip(samples) {
+        let Ok(mut sample) = sample else { continue };
+        let previous = previous_cpu.insert(id.to_string(), sample.cpu_stats.clone());
+        let diffed = previous.is_some();
+        if let Some(previous) = previous {
+            sample.precpu_stats = previous;
+        }
+
+        let container_stats = container_stats_from_sample(docker, id, name, &sample, false, false).await;
+        // The first sighting has nothing to diff against, so don't let it stand in for a real sample
+        if diffed {
+            cache.insert(container_stats.clone());
+        }
+        stats.push(container_stats);
+    }
+
+    // Forget readings of containers that stopped
+    previous_cpu.retain(|id, _| running.iter().any(|container| container.id.as_deref() == Some(id.as_str())));
+
+    stats.sort_by(|a, b| b.cpu_percentage.total_cmp(&a.cpu_percentage));
+    stats.truncate(DASHBOARD_TOP_CONTAINERS);
+    stats
+}
+
+async fn build_dashboard_update(
+    docker_client: &DockerClient,
+    monitor: &SystemMonitor,
+    stats_cache: &StatsCache,
+    previous_cpu: &mut HashMap<String, bollard::container::CPUStats>,
+) -> DashboardUpdate {
+    let sampler = monitor.clone();
+    let system = tokio::task::spawn_blocking(move || read_system_stats(&sampler))
+        .await
+        .unwrap_or_else(|_| read_system_stats(monitor));
+
+    let (docker_info, top_containers) = match docker_client.get().await {
+        Ok(docker) => (
+            collect_docker_system_info(&docker).await.ok(),
+            collect_top_container_stats(&docker, stats_cache, previous_cpu).await,
+        ),
+        Err(e) => {
+            eprintln!("Dashboard feed failed to connect to Docker: {}", e);
+            (None, Vec::new())
+        }
+    };
+
+    DashboardUpdate {
+        system,
+        docker: docker_info,
+        top_containers,
+    }
+}
+
+#[tauri::command]
+async fn start_dashboard_feed(
+    app_handle: tauri::AppHandle,
+    feed: tauri::State<'_, DashboardFeedState>,
+    cancellation: tauri::State<'_, StreamCancellation>,
+) -> Result<String, String> {
+    let mut handle = feed.0.lock().map_err(|_| "Dashboard feed state is poisoned".to_string())?;
+
+    if handle.as_ref().map(|h| !h.is_finished()).unwrap_or(false) {
+        return Ok("Dashboard feed already running".to_string());
+    }
+
+    let mut token = cancellation.token();
+
+    *handle = Some(tokio::spawn(async move {
+        let mut period = app_handle.state::<Settings>().poll_interval();
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut was_connected = true;
+        let mut previous_cpu = HashMap::new();
+
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            let update = build_dashboard_update(
+                app_handle.state::<DockerClient>().inner(),
+                app_handle.state::<SystemMonitor>().inner(),
+                app_handle.state::<StatsCache>().inner(),
+                &mut previous_cpu,
+            )
+            .await;
+
+            // Streams on a dead daemon connection would only hang or error out
+            let connected = update.docker.is_some();
+            if was_connected && !connected {
+                let cancellation = app_handle.state::<StreamCancellation>();
+                cancellation.cancel_all();
+                token = cancellation.token();
+                let _ = app_handle.emit("docker-disconnected", "Lost connection to the Docker daemon");
+            }
+            was_connected = connected;
+
+            if let Err(e) = app_handle.emit("dashboard-update", update) {
+                eprintln!("Failed to emit dashboard update: {}", e);
+                break;
+            }
+
+            // Pick up interval changes made through `set_poll_interval`
+            let current = app_handle.state::<Settings>().poll_interval();
+            if current != period {
+                period = current;
+                interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            }
+        }
+    }));
+
+    Ok("Dashboard feed started".to_string())
+}
+
+#[tauri::command]
+async fn stop_dashboard_feed(feed: tauri::State<'_, DashboardFeedState>) -> Result<String, String> {
+    let mut handle = feed.0.lock().map_err(|_| "Dashboard feed state is poisoned".to_string())?;
+
+    match handle.take() {
+        Some(task) => {
+            task.abort();
+            Ok("Dashboard feed stopped".to_string())
+        }
+        None => Ok("Dashboard feed was not running".to_string()),
+    }
+}
+
+// Log drivers the daemon can always read back through the logs API
+const READABLE_LOG_DRIVERS: [&str; 3] = ["json-file", "local", "journald"];
+
+// Why the logs API can't return this container's output, if it can't. Other drivers are
+// readable through the daemon's dual-logging cache unless that cache is disabled.
+async fn unreadable_log_reason(docker: &Docker, container_id: &str) -> Result<Option<String>, String> {
+    let inspect_result = docker
+        .inspect_container(container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+
+    let Some(log_config) = inspect_result.host_config.and_then(|host_config| host_config.log_config) else {
+        return Ok(None);
+    };
+    let driver = log_config.typ.unwrap_or_default();
+
+    if driver == "none" {
+        return Ok(Some("logging is disabled (driver is 'none')".to_string()));
+    }
+    if driver.is_empty() || READABLE_LOG_DRIVERS.contains(&driver.as_str()) {
+        return Ok(None);
+    }
+
+    let cache_disabled = log_config
+        .config
+        .and_then(|config| config.get("cache-disabled").cloned())
+        .is_some_and(|value| value == "true");
+    if cache_disabled {
+        return Ok(Some(format!(
+            "driver is '{}' and its local cache is disabled, view logs in the driver's destination",
+            driver
+        )));
+    }
+
+    Ok(None)
+}
+
+#[tauri::command]
+async fn get_container_logs(
+    container_id: String,
+    tail: Option<u64>,
+    follow: Option<bool>,
+    since: Option<i64>,
+    until: Option<i64>,
+    grep: Option<String>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<LogEntry>, String> {
+    if let (Some(since), Some(until)) = (since, until) {
+        if since > until {
+            return Err("The start of the time window must be before its end".to_string());
+        }
+    }
+
+    let docker = docker_client.get().await?;
+
+    if let Some(reason) = unreadable_log_reason(&docker, &container_id).await? {
+        return Ok(vec![LogEntry {
+            timestamp: String::new(),
+            stream: "system".to_string(),
+            message: format!("Logs unavailable: {}", reason),
+        }]);
+    }
+
+    let tail_value = tail.unwrap_or(0);
+    let logs_options = LogsOptions::<String> {
+        stdout: true,
+        stderr: true,
+        timestamps: true,
+        tail: if tail_value == 0 { "all".to_string() } else { tail_value.to_string() },
+        follow: follow.unwrap_or(false),
+        // Unix seconds; 0 leaves the bound open
+        since: since.unwrap_or(0),
+        until: until.unwrap_or(0),
+        ..Default::default()
+    };
+
+    let mut log_stream = docker.logs(&container_id, Some(logs_options));
+    let mut logs = Vec::new();
+
+    // Collect logs from the stream
+    while let Some(log_result) = log_stream.next().await {
+        match log_result {
+            // bollard has already split the multiplexed stream into frames and removed their
+            // 8-byte headers, tagging each as stdout or stderr (TTY containers arrive unframed
+            // as `Console`)
+            Ok(log_output) => logs.extend(log_entries_from_output(log_output)),
+            Err(e) => {
+                eprintln!("Error reading log: {}", e);
+                break;
+            }
+        }
+    }
+
+    match grep.map(|pattern| pattern.to_lowercase()).filter(|pattern| !pattern.is_empty()) {
+        Some(pattern) => Ok(logs
+            .into_iter()
+            .filter(|entry| entry.message.to_lowercase().contains(&pattern))
+            .collect()),
+        None => Ok(logs),
+    }
+}
+
+// A TTY container's `Console` chunk can hold several timestamped lines, so split the
+// payload rather than treating each frame as one line
+fn log_entries_from_output(output: bollard::container::LogOutput) -> Vec<LogEntry> {
+    let stream = match output {
+        bollard::container::LogOutput::StdErr { .. } => "stderr",
+        bollard::container::LogOutput::StdIn { .. } => "stdin",
+        _ => "stdout",
+    };
+
+    String::from_utf8_lossy(&output.into_bytes())
+        .lines()
+        .map(|line| {
+            let line = line.trim_end_matches('\r');
+            let (timestamp, message) = line.split_once(' ').unwrap_or((line, ""));
+            LogEntry {
+                timestamp: timestamp.to_string(),
+                stream: stream.to_string(),
+                message: message.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn log_entry_from_output(output: bollard::container::LogOutput) -> LogEntry {
+    let stream = match output {
+        bollard::container::LogOutput::StdErr { .. } => "stderr",
+        bollard::container::LogOutput::StdIn { .. } => "stdin",
+        _ => "stdout",
+    };
+    let line = String::from_utf8_lossy(&output.into_bytes()).trim_end_matches(['\r', '\n']).to_string();
+    let (timestamp, message) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+
+    LogEntry {
+        timestamp: timestamp.to_string(),
+        stream: stream.to_string(),
+        message: message.to_string(),
+    }
+}
+
+// Initial look-back of a log page; doubled until the window holds a full page
+const LOG_PAGE_INITIAL_WINDOW_SECS: i64 = 60;
+
+// Every log line in [since, before) with its stream tag. `since` of 0 reads from the start.
+async fn fetch_log_window(
+    docker: &Docker,
+    container_id: &str,
+    since: i64,
+    before_timestamp: Option<i64>,
+) -> Result<Vec<LogEntry>, String> {
+    // No `tail` here: the json-file and local drivers apply it to the whole log before
+    // the `until` cut, so older pages would come back empty
+    let logs_options = LogsOptions::<String> {
+        stdout: true,
+        stderr: true,
+        timestamps: true,
+        since,
+        until: before_timestamp.unwrap_or(0),
+        tail: "all".to_string(),
+        ..Default::default()
+    };
+
+    let mut log_stream = docker.logs(container_id, Some(logs_options));
+    let mut lines = Vec::new();
+
+    while let Some(log_result) = log_stream.next().await {
+        let output = log_result.map_err(|e| format!("Failed to read logs: {}", e))?;
+        let entry = log_entry_from_output(output);
+
+        // `until` is inclusive of the exact cursor second boundary
+        if let (Some(cursor), Some(secs)) = (before_timestamp, parse_rfc3339_secs(&entry.timestamp)) {
+            if secs >= cursor {
+                continue;
+            }
+        }
+        lines.push(entry);
+    }
+
+    Ok(lines)
+}
+
+// Pages backwards through a container's log. Cursors are Unix seconds: a page holds lines
+// strictly before its cursor, and lines sharing the oldest second are deferred to the next
+// page so that a second is never split across two pages.
+#[tauri::command]
+async fn get_logs_page(
+    container_id: String,
+    before_timestamp: Option<i64>,
+    limit: u64,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<LogPage, String> {
+    if limit == 0 {
+        return Err("Page limit must be greater than zero".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    // Widen a `since` window backwards from the cursor until it holds a full page or
+    // covers the whole log
+    let end = before_timestamp.unwrap_or_else(|| unix_timestamp() + 1);
+    let mut window = LOG_PAGE_INITIAL_WINDOW_SECS;
+    let mut lines = loop {
+        let since = (end - window).max(0);
+        let lines = fetch_log_window(&docker, &container_id, since, before_timestamp).await?;
+
+        if since == 0 {
+            if (lines.len() as u64) <= limit {
+                // The whole remaining log fits in this page
+                return Ok(LogPage { lines, next_cursor: None });
+            }
+            break lines;
+        }
+        if (lines.len() as u64) >= limit {
+            break lines;
+        }
+        window = window.saturating_mul(2);
+    };
+
+    let excess = lines.len().saturating_sub(limit as usize);
+    lines.drain(..excess);
+
+    let oldest_second = lines.first().and_then(|entry| parse_rfc3339_secs(&entry.timestamp));
+    let next_cursor = match oldest_second {
+        Some(oldest) if lines.iter().any(|entry| parse_rfc3339_secs(&entry.timestamp) != Some(oldest)) => {
+            lines.retain(|entry| parse_rfc3339_secs(&entry.timestamp) != Some(oldest));
+            Some(oldest + 1)
+        }
+        // Every line shares one second, so the rest of it can't be reached separately
+        other => other,
+    };
+
+    Ok(LogPage { lines, next_cursor })
+}
+
+#[tauri::command]
+async fn count_log_matches(
+    container_id: String,
+    patterns: Vec<String>,
+    since: Option<i64>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<HashMap<String, u64>, String> {
+    if patterns.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // A RegexSet tests every pattern against a line in one pass
+    let pattern_set = regex::RegexSet::new(&patterns).map_err(|e| format!("Invalid pattern: {}", e))?;
+
+    let docker = docker_client.get().await?;
+
+    let logs_options = LogsOptions::<String> {
+        stdout: true,
+        stderr: true,
+        since: since.unwrap_or(0),
+        tail: "all".to_string(),
+        ..Default::default()
+    };
+
+    let mut counts = vec![0u64; patterns.len()];
+    let mut log_stream = docker.logs(&container_id, Some(logs_options));
+
+    while let Some(log_result) = log_stream.next().await {
+        let output = log_result.map_err(|e| format!("Failed to read logs: {}", e))?;
+        let bytes = output.into_bytes();
+        for line in String::from_utf8_lossy(&bytes).lines() {
+            for index in pattern_set.matches(line) {
+                counts[index] += 1;
+            }
+        }
+    }
+
+    Ok(patterns.into_iter().zip(counts).collect())
+}
+
+#[tauri::command]
+async fn start_log_stream(
+    container_id: String,
+    app_handle: tauri::AppHandle,
+    cancellation: tauri::State<'_, StreamCancellation>,
+    log_streams: tauri::State<'_, LogStreams>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
+    let token = cancellation.token();
+
+    let logs_options = LogsOptions::<String> {
+        stdout: true,
+        stderr: true,
+        timestamps: true,
+        tail: "all".to_string(),
+        follow: true, // This enables streaming
+        ..Default::default()
+    };
+
+    let container_id_clone = container_id.clone();
+    let app_handle_clone = app_handle.clone();
+    let sequence = LOG_STREAM_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+    // Hold the lock while spawning so the task can't finish and clean up before its entry exists
+    let mut streams = log_streams.0.lock().map_err(|_| "Failed to lock log streams".to_string())?;
+
+    // Spawn a background task to stream logs
+    let task = tokio::spawn(async move {
+        let mut log_stream = docker.logs(&container_id_clone, Some(logs_options));
+        
+        while let Some(log_result) = tokio::select! {
+            _ = token.cancelled() => None,
+            next = log_stream.next() => next,
+        } {
+            match log_result {
+                Ok(log_output) => {
+                    // Frames arrive demultiplexed with their headers removed, tagged by stream
+                    let entries = log_entries_from_output(log_output);
+
+                    // Emit the log lines to the frontend
+                    let event_name = format!("log-stream-{}", container_id_clone);
+                    let emitted = entries.into_iter().try_for_each(|entry| app_handle_clone.emit(&event_name, entry));
+                    if let Err(e) = emitted {
+                        eprintln!("Failed to emit log event: {}", e);
+                        break;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error reading log stream: {}", e);
+                    // Emit error event
+                    let _ = app_handle_clone.emit(&format!("log-stream-error-{}", container_id_clone), format!("Log stream error: {}", e));
+                    break;
+                }
+            }
+        }
+        
+        app_handle_clone.state::<LogStreams>().remove(&container_id_clone, sequence);
+
+        // Emit stream ended event
+        let _ = app_handle_clone.emit(&format!("log-stream-ended-{}", container_id_clone), "Log stream ended");
+    });
+
+    // Restarting a stream replaces the previous one for the same container
+    if let Some((_, previous)) = streams.insert(container_id, (sequence, task.abort_handle())) {
+        previous.abort();
+    }
+
+    Ok("Log stream started".to_string())
+}
+
+#[tauri::command]
+async fn stop_log_stream(container_id: String, log_streams: tauri::State<'_, LogStreams>) -> Result<String, String> {
+    let stream = log_streams.0.lock().ok().and_then(|mut streams| streams.remove(&container_id));
+
+    match stream {
+        Some((_, handle)) => {
+            handle.abort();
+            Ok("Log stream stopped".to_string())
+        }
+        None => Ok("No log stream running for this container".to_string()),
+    }
+}
+
+// Lines of history each container contributes when a merged stream starts
+const MERGED_LOG_TAIL: &str = "200";
+
+// Token for the running merged log stream; a child of the global stream token so
+// `cancel_all_streams` stops it too
+#[derive(Default)]
+pub struct MergedLogStream(std::sync::Mutex<Option<CancellationToken>>);
+
+impl MergedLogStream {
+    fn replace(&self, token: Option<CancellationToken>) {
+        if let Ok(mut current) = self.0.lock() {
+            if let Some(previous) = std::mem::replace(&mut *current, token) {
+                previous.cancel();
             }
+        }
+    }
+}
+
+// `docker compose logs -f` style feed: every container's log on the single `merged-logs` event
+#[tauri::command]
+async fn start_merged_log_stream(
+    container_ids: Vec<String>,
+    app_handle: tauri::AppHandle,
+    cancellation: tauri::State<'_, StreamCancellation>,
+    merged_stream: tauri::State<'_, MergedLogStream>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    if container_ids.is_empty() {
+        return Err("No containers selected".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    let mut sources = Vec::new();
+    for container_id in container_ids {
+        let inspect_result = docker
+            .inspect_container(&container_id, None)
+            .await
+            .map_err(|e| format!("Failed to inspect container {}: {}", container_id, e))?;
+        let name = inspect_result
+            .name
+            .map(|name| name.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| container_id.clone());
+        sources.push((container_id, name));
+    }
+
+    // Starting a new merged stream replaces the previous one
+    let token = cancellation.token().child_token();
+    merged_stream.replace(Some(token.clone()));
+
+    let source_count = sources.len();
+    let tasks: Vec<_> = sources
+        .into_iter()
+        .enumerate()
+        .map(|(color_index, (container_id, container_name))| {
+            let docker = docker.clone();
+            let token = token.clone();
+            let app_handle = app_handle.clone();
+
+            tokio::spawn(async move {
+                let mut log_stream = docker.logs(&container_id, Some(LogsOptions::<String> {
+                    stdout: true,
+                    stderr: true,
+                    timestamps: true,
+                    tail: MERGED_LOG_TAIL.to_string(),
+                    follow: true,
+                    ..Default::default()
+                }));
+
+                while let Some(log_result) = tokio::select! {
+                    _ = token.cancelled() => None,
+                    next = log_stream.next() => next,
+                } {
+                    match log_result {
+                        Ok(output) => {
+                            let entry = log_entry_from_output(output);
+                            let line = MergedLogLine {
+                                container_id: container_id.clone(),
+                                container_name: container_name.clone(),
+                                color_index,
+                                timestamp: entry.timestamp,
+                                stream: entry.stream,
+                                message: entry.message,
+                            };
+                            if app_handle.emit("merged-logs", line).is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            let _ = app_handle.emit(
+                                "merged-logs-error",
+                                format!("Log stream error for {}: {}", container_name, e),
+                            );
+                            break;
+                        }
+                    }
+                }
+            })
         })
         .collect();
 
-    Ok(network_info)
+    tokio::spawn(async move {
+        futures_util::future::join_all(tasks).await;
+        let _ = app_handle.emit("merged-logs-ended", "Merged log stream ended");
+    });
+
+    Ok(format!("Merged log stream started for {} containers", source_count))
+}
+
+#[tauri::command]
+async fn stop_merged_log_stream(merged_stream: tauri::State<'_, MergedLogStream>) -> Result<String, String> {
+    merged_stream.replace(None);
+    Ok("Merged log stream stopped".to_string())
 }
 
 #[tauri::command]
-async fn remove_network(network_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn inspect_container(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<serde_json::Value, String> {
+    let docker = docker_client.get().await?;
 
-    docker
-        .remove_network(&network_id)
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
         .await
-        .map_err(|e| format!("Failed to remove network: {}", e))?;
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-    Ok(format!("Network {} removed successfully", network_id))
+    // Convert the inspect result to a JSON value for easy frontend handling
+    serde_json::to_value(inspect_result)
+        .map_err(|e| format!("Failed to serialize inspect data: {}", e))
 }
 
+// Inspect many containers concurrently over one connection. Containers that fail to inspect
+// (e.g. removed in the meantime) are left out of the map.
 #[tauri::command]
-async fn execute_command(command: String) -> Result<TerminalOutput, String> {
-    // Parse the command string into command and arguments
-    let parts: Vec<&str> = command.trim().split_whitespace().collect();
-    if parts.is_empty() {
-        return Err("Empty command".to_string());
+async fn batch_inspect(
+    container_ids: Vec<String>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<HashMap<String, serde_json::Value>, String> {
+    let docker = docker_client.get().await?;
+
+    let inspected = futures_util::future::join_all(
+        container_ids.iter().map(|container_id| docker.inspect_container(container_id, None)),
+    )
+    .await;
+
+    let mut results = HashMap::new();
+    for (container_id, inspect_result) in container_ids.into_iter().zip(inspected) {
+        let Ok(inspect_result) = inspect_result else { continue };
+        let value = serde_json::to_value(inspect_result)
+            .map_err(|e| format!("Failed to serialize inspect data: {}", e))?;
+        results.insert(container_id, value);
     }
 
-    let cmd = parts[0];
-    let args: Vec<&str> = if parts.len() > 1 { parts[1..].to_vec() } else { vec![] };
+    Ok(results)
+}
 
-    // Create the command
-    let mut tokio_cmd = TokioCommand::new(cmd);
-    tokio_cmd.args(&args);
-    tokio_cmd.stdout(Stdio::piped());
-    tokio_cmd.stderr(Stdio::piped());
+#[tauri::command]
+async fn reconstruct_run_command(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    let docker = docker_client.get().await?;
 
-    // Execute the command
-    match tokio_cmd.output().await {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            let exit_code = output.status.code();
-            let success = output.status.success();
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-            Ok(TerminalOutput {
-                stdout,
-                stderr,
-                exit_code,
-                success,
-            })
-        }
-        Err(e) => Err(format!("Failed to execute command: {}", e)),
-    }
-}
+    let config = inspect_result.config.unwrap_or_default();
+    let host_config = inspect_result.host_config.unwrap_or_default();
+    let image = config.image.clone().unwrap_or_default();
 
-#[tauri::command]
-async fn get_current_directory() -> Result<String, String> {
-    match std::env::current_dir() {
-        Ok(path) => Ok(path.to_string_lossy().to_string()),
-        Err(e) => Err(format!("Failed to get current directory: {}", e)),
+    // Values baked into the image don't need repeating on the command line
+    let image_config = docker
+        .inspect_image(&image)
+        .await
+        .ok()
+        .and_then(|inspect| inspect.config)
+        .unwrap_or_default();
+    let image_env = image_config.env.unwrap_or_default();
+
+    let mut args: Vec<String> = vec!["docker".into(), "run".into(), "-d".into()];
+
+    if let Some(name) = inspect_result.name.as_deref().map(|n| n.trim_start_matches('/')).filter(|n| !n.is_empty()) {
+        args.extend(["--name".to_string(), name.to_string()]);
+    }
+    if config.tty == Some(true) {
+        args.push("-t".into());
+    }
+    if config.open_stdin == Some(true) {
+        args.push("-i".into());
+    }
+    if host_config.init == Some(true) {
+        args.push("--init".into());
+    }
+    if let Some(user) = config.user.filter(|u| !u.is_empty() && Some(u) != image_config.user.as_ref()) {
+        args.extend(["--user".to_string(), user]);
+    }
+    if let Some(working_dir) = config.working_dir.filter(|w| !w.is_empty() && Some(w) != image_config.working_dir.as_ref()) {
+        args.extend(["--workdir".to_string(), working_dir]);
     }
-}
 
-#[tauri::command]
-async fn get_home_directory() -> Result<String, String> {
-    match std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
-        Ok(home) => Ok(home),
-        Err(_) => Err("Failed to get home directory".to_string()),
+    if let Some(network_mode) = host_config.network_mode.filter(|mode| !matches!(mode.as_str(), "" | "default" | "bridge")) {
+        args.extend(["--network".to_string(), network_mode]);
     }
-}
 
-#[tauri::command]
-async fn set_working_directory(session_id: String, path: String) -> Result<String, String> {
-    // For now, we'll just return success. In a real implementation,
-    // we'd maintain per-session working directories
-    Ok(format!("Working directory set to {} for session {}", path, session_id))
-}
+    if let Some(policy) = host_config.restart_policy.and_then(|policy| {
+        let retries = policy.maximum_retry_count.unwrap_or(0);
+        policy.name.map(|name| (name, retries))
+    }) {
+        match policy {
+            (bollard::models::RestartPolicyNameEnum::ON_FAILURE, retries) if retries > 0 => {
+                args.extend(["--restart".to_string(), format!("on-failure:{}", retries)]);
+            }
+            (bollard::models::RestartPolicyNameEnum::EMPTY, _) | (bollard::models::RestartPolicyNameEnum::NO, _) => {}
+            (name, _) => args.extend(["--restart".to_string(), name.to_string()]),
+        }
+    }
 
-#[tauri::command]
-async fn change_directory(path: String) -> Result<String, String> {
-    match std::env::set_current_dir(&path) {
-        Ok(_) => Ok(format!("Changed directory to: {}", path)),
-        Err(e) => Err(format!("Failed to change directory: {}", e)),
+    let mut port_bindings: Vec<(String, Vec<bollard::models::PortBinding>)> = host_config
+        .port_bindings
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(port, bindings)| (port, bindings.unwrap_or_default()))
+        .collect();
+    port_bindings.sort_by(|a, b| a.0.cmp(&b.0));
+    for (container_port, bindings) in port_bindings {
+        let container_port = container_port.strip_suffix("/tcp").unwrap_or(&container_port).to_string();
+        for binding in bindings {
+            let host_port = binding.host_port.unwrap_or_default();
+            let mapping = match binding.host_ip.filter(|ip| !ip.is_empty() && ip != "0.0.0.0") {
+                Some(ip) => format!("{}:{}:{}", ip, host_port, container_port),
+                None if host_port.is_empty() => container_port.clone(),
+                None => format!("{}:{}", host_port, container_port),
+            };
+            args.extend(["-p".to_string(), mapping]);
+        }
     }
-}
 
-#[tauri::command]
-async fn execute_docker_command(args: Vec<String>) -> Result<TerminalOutput, String> {
-    let mut cmd = TokioCommand::new("docker");
-    cmd.args(&args);
-    cmd.stdout(Stdio::piped());
-    cmd.stderr(Stdio::piped());
+    for bind in host_config.binds.unwrap_or_default() {
+        args.extend(["-v".to_string(), bind]);
+    }
 
-    match cmd.output().await {
-        Ok(output) => {
-            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            let exit_code = output.status.code();
-            let success = output.status.success();
+    // Compose and `--mount` put their volumes here rather than in Binds
+    for mount in host_config.mounts.unwrap_or_default() {
+        let Some(target) = mount.target.filter(|target| !target.is_empty()) else { continue };
+        let mut spec = vec![format!("type={}", mount.typ.map(|typ| typ.to_string()).unwrap_or_else(|| "volume".to_string()))];
+        if let Some(source) = mount.source.filter(|source| !source.is_empty()) {
+            spec.push(format!("source={}", source));
+        }
+        spec.push(format!("target={}", target));
+        if mount.read_only == Some(true) {
+            spec.push("readonly".to_string());
+        }
+        args.extend(["--mount".to_string(), spec.join(",")]);
+    }
 
-            Ok(TerminalOutput {
-                stdout,
-                stderr,
-                exit_code,
-                success,
-            })
+    for env in config.env.unwrap_or_default() {
+        if !image_env.contains(&env) {
+            args.extend(["-e".to_string(), env]);
         }
-        Err(e) => Err(format!("Failed to execute docker command: {}", e)),
     }
-}
 
-#[tauri::command]
-async fn get_system_stats() -> Result<SystemStats, String> {
-    let mut sys = System::new_all();
-    sys.refresh_all();
-    
-    let cpu_usage = sys.global_cpu_info().cpu_usage();
-    let memory_used = sys.used_memory();
-    let memory_total = sys.total_memory();
-    
-    // Use placeholder disk values for now - sysinfo disk API has changed
-    let disk_used: u64 = 50 * 1024 * 1024 * 1024; // 50GB used as placeholder
-    let disk_total: u64 = 1000 * 1024 * 1024 * 1024; // 1TB total as placeholder
-    
-    let cpu_count = sys.cpus().len();
-    
-    Ok(SystemStats {
-        cpu_usage,
-        memory_used,
-        memory_total,
-        memory_used_gb: memory_used as f64 / 1_073_741_824.0, // Convert to GB
-        memory_total_gb: memory_total as f64 / 1_073_741_824.0,
-        disk_used,
-        disk_total,
-        disk_used_gb: disk_used as f64 / 1_073_741_824.0,
-        disk_total_gb: disk_total as f64 / 1_073_741_824.0,
-        cpu_count,
-    })
+    // Entrypoint and command only need spelling out when they override the image
+    let mut trailing_args = Vec::new();
+    let entrypoint = config.entrypoint.unwrap_or_default();
+    let entrypoint_overridden = !entrypoint.is_empty() && Some(&entrypoint) != image_config.entrypoint.as_ref();
+    if entrypoint_overridden {
+        args.extend(["--entrypoint".to_string(), entrypoint[0].clone()]);
+        trailing_args.extend(entrypoint[1..].iter().cloned());
+    }
+    // `--entrypoint` resets the image's CMD, so the command must always follow it
+    let cmd = config.cmd.unwrap_or_default();
+    if entrypoint_overridden || Some(&cmd) != image_config.cmd.as_ref() {
+        trailing_args.extend(cmd);
+    }
+
+    args.push(image);
+    args.extend(trailing_args);
+
+    Ok(args.iter().map(|arg| shell_quote(arg)).collect::<Vec<_>>().join(" "))
 }
 
+// Single label lookup; the filtered listing is much lighter than a full inspect
 #[tauri::command]
-async fn get_docker_system_info() -> Result<DockerSystemInfo, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn get_label(
+    container_id: String,
+    key: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Option<String>, String> {
+    let docker = docker_client.get().await?;
 
-    // Get containers
     let containers = docker
         .list_containers(Some(ListContainersOptions::<String> {
             all: true,
+            filters: HashMap::from([("id".to_string(), vec![container_id.clone()])]),
             ..Default::default()
         }))
         .await
         .map_err(|e| format!("Failed to list containers: {}", e))?;
 
-    let containers_total = containers.len();
-    let containers_running = containers
-        .iter()
-        .filter(|c| c.state.as_ref().map(|s| s == "running").unwrap_or(false))
-        .count();
-    let containers_stopped = containers_total - containers_running;
-
-    // Get images
-    let images = docker
-        .list_images(Some(ListImagesOptions::<String> {
-            all: true,
-            ..Default::default()
-        }))
-        .await
-        .map_err(|e| format!("Failed to list images: {}", e))?;
-
-    // Get volumes
-    let volumes_response = docker
-        .list_volumes(Some(ListVolumesOptions::<String> {
-            ..Default::default()
-        }))
-        .await
-        .map_err(|e| format!("Failed to list volumes: {}", e))?;
-
-    let volumes_total = volumes_response.volumes.unwrap_or_default().len();
+    if let Some(container) = containers.into_iter().next() {
+        return Ok(container.labels.and_then(|mut labels| labels.remove(&key)));
+    }
 
-    // Get networks
-    let networks = docker
-        .list_networks(Some(ListNetworksOptions::<String> {
-            ..Default::default()
-        }))
+    // The id filter only matches id prefixes, so fall back to inspect for names
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
         .await
-        .map_err(|e| format!("Failed to list networks: {}", e))?;
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-    Ok(DockerSystemInfo {
-        containers_running,
-        containers_stopped,
-        containers_total,
-        images_total: images.len(),
-        volumes_total,
-        networks_total: networks.len(),
-    })
+    Ok(inspect_result
+        .config
+        .and_then(|config| config.labels)
+        .and_then(|mut labels| labels.remove(&key)))
 }
 
 #[tauri::command]
-async fn remove_container(container_id: String, force: Option<bool>) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
-
-    let options = Some(RemoveContainerOptions {
-        force: force.unwrap_or(false),
-        ..Default::default()
-    });
+async fn container_uptime(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ContainerUptime, String> {
+    let docker = docker_client.get().await?;
 
-    docker
-        .remove_container(&container_id, options)
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
         .await
-        .map_err(|e| format!("Failed to remove container: {}", e))?;
-
-    Ok(format!("Container {} removed successfully", container_id))
-}
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-#[tauri::command]
-async fn pause_container(container_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+    let state = inspect_result.state.unwrap_or_default();
+    let started_at = state.started_at.unwrap_or_default();
 
-    docker
-        .pause_container(&container_id)
-        .await
-        .map_err(|e| format!("Failed to pause container: {}", e))?;
+    let uptime_secs = if state.running == Some(true) {
+        parse_rfc3339_secs(&started_at).map(|started| (unix_timestamp() - started).max(0) as u64)
+    } else {
+        None
+    };
 
-    Ok(format!("Container {} paused successfully", container_id))
+    Ok(ContainerUptime {
+        started_at,
+        uptime_secs,
+        restart_count: inspect_result.restart_count.unwrap_or(0),
+    })
 }
 
-#[tauri::command]
-async fn unpause_container(container_id: String) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
-
-    docker
-        .unpause_container(&container_id)
-        .await
-        .map_err(|e| format!("Failed to unpause container: {}", e))?;
-
-    Ok(format!("Container {} unpaused successfully", container_id))
-}
+// Label prefixes set by Docker itself, image build tooling and metadata conventions
+const SYSTEM_LABEL_PREFIXES: [&str; 5] = [
+    "org.opencontainers.",
+    "org.label-schema.",
+    "com.docker.",
+    "desktop.docker.io/",
+    "maintainer",
+];
 
 #[tauri::command]
-async fn get_container_stats(container_id: String) -> Result<ContainerStats, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn categorize_labels(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<CategorizedLabels, String> {
+    let docker = docker_client.get().await?;
 
-    // Get container info first to get the name
-    let containers = docker
-        .list_containers(Some(ListContainersOptions::<String> {
-            all: true,
-            filters: {
-                let mut filters = HashMap::new();
-                filters.insert("id".to_string(), vec![container_id.clone()]);
-                filters
-            },
-            ..Default::default()
-        }))
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
         .await
-        .map_err(|e| format!("Failed to get container info: {}", e))?;
-
-    let container_name = containers
-        .first()
-        .and_then(|c| c.names.as_ref())
-        .and_then(|names| names.first())
-        .map(|name| name.trim_start_matches('/').to_string())
-        .unwrap_or_else(|| format!("container-{}", &container_id[..8]));
-
-    // Get real-time stats from Docker
-    let mut stats_stream = docker.stats(&container_id, Some(bollard::container::StatsOptions {
-        stream: false,
-        one_shot: true,
-    }));
-    
-    if let Some(Ok(stats)) = stats_stream.next().await {
-        // Calculate CPU percentage - simplified approach
-        let cpu_stats = &stats.cpu_stats;
-        let precpu_stats = &stats.precpu_stats;
-        
-        let cpu_delta = cpu_stats.cpu_usage.total_usage.saturating_sub(precpu_stats.cpu_usage.total_usage);
-        let system_delta = cpu_stats.system_cpu_usage.unwrap_or(0).saturating_sub(precpu_stats.system_cpu_usage.unwrap_or(0));
-        let online_cpus = cpu_stats.online_cpus.unwrap_or(1) as f64;
-        
-        let cpu_percentage = if system_delta > 0 && cpu_delta > 0 {
-            (cpu_delta as f64 / system_delta as f64) * online_cpus * 100.0
-        } else {
-            0.0
-        };
-
-        // Memory stats
-        let memory_usage = stats.memory_stats.usage.unwrap_or(0);
-        let memory_limit = stats.memory_stats.limit.unwrap_or(0);
-        let memory_percentage = if memory_limit > 0 {
-            (memory_usage as f64 / memory_limit as f64) * 100.0
-        } else {
-            0.0
-        };
-
-        // Network stats
-        let (network_rx, network_tx) = if let Some(networks) = &stats.networks {
-            let mut rx_bytes = 0u64;
-            let mut tx_bytes = 0u64;
-            
-            for (_, network) in networks {
-                rx_bytes += network.rx_bytes;
-                tx_bytes += network.tx_bytes;
-            }
-            
-            (rx_bytes, tx_bytes)
-        } else {
-            (0, 0)
-        };
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-        // Block I/O stats
-        let (block_read, block_write) = if let Some(io_service_bytes_recursive) = &stats.blkio_stats.io_service_bytes_recursive {
-            let mut read_bytes = 0u64;
-            let mut write_bytes = 0u64;
-            
-            for io_stat in io_service_bytes_recursive {
-                match io_stat.op.as_str() {
-                    "read" | "Read" => read_bytes += io_stat.value,
-                    "write" | "Write" => write_bytes += io_stat.value,
-                    _ => {}
-                }
-            }
-            
-            (read_bytes, write_bytes)
+    let labels = inspect_result.config.and_then(|config| config.labels).unwrap_or_default();
+    let mut categorized = CategorizedLabels::default();
+
+    for (key, value) in labels {
+        // Compose is checked before the broader com.docker. system prefix
+        let bucket = if key.starts_with("com.docker.compose.") {
+            &mut categorized.compose
+        } else if key.starts_with("traefik.") {
+            &mut categorized.traefik
+        } else if SYSTEM_LABEL_PREFIXES.iter().any(|prefix| key.starts_with(prefix)) {
+            &mut categorized.system
         } else {
-            (0, 0)
+            &mut categorized.custom
         };
-
-        Ok(ContainerStats {
-            id: container_id,
-            name: container_name,
-            cpu_percentage,
-            memory_usage,
-            memory_limit,
-            memory_percentage,
-            network_rx,
-            network_tx,
-            block_read,
-            block_write,
-        })
-    } else {
-        Err("Failed to get container stats".to_string())
+        bucket.insert(key, value);
     }
+
+    Ok(categorized)
 }
 
 #[tauri::command]
-async fn get_container_logs(container_id: String, tail: Option<u64>, follow: Option<bool>) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
-
-    let tail_value = tail.unwrap_or(0);
-    let logs_options = LogsOptions::<String> {
-        stdout: true,
-        stderr: true,
-        timestamps: true,
-        tail: if tail_value == 0 { "all".to_string() } else { tail_value.to_string() },
-        follow: follow.unwrap_or(false),
-        ..Default::default()
-    };
+async fn container_all_ports(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<ContainerPort>, String> {
+    let docker = docker_client.get().await?;
 
-    let mut log_stream = docker.logs(&container_id, Some(logs_options));
-    let mut logs = String::new();
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-    // Collect logs from the stream
-    while let Some(log_result) = log_stream.next().await {
-        match log_result {
-            Ok(log_output) => {
-                // Convert log output to string
-                let bytes = log_output.into_bytes();
-                let log_str = String::from_utf8_lossy(&bytes);
-                
-                // Clean up Docker log format - remove the first 8 bytes which contain Docker headers
-                let cleaned_log = if bytes.len() > 8 {
-                    String::from_utf8_lossy(&bytes[8..])
-                } else {
-                    log_str
-                };
-                
-                logs.push_str(&cleaned_log);
-            }
-            Err(e) => {
-                eprintln!("Error reading log: {}", e);
-                break;
-            }
-        }
-        
-        // If not following, break after collecting initial logs
-        if !follow.unwrap_or(false) {
-            break;
+    // Keys look like "80/tcp"; EXPOSEd ports that were never published have no bindings
+    let mut port_keys: Vec<String> = inspect_result
+        .config
+        .and_then(|config| config.exposed_ports)
+        .map(|exposed| exposed.into_keys().collect())
+        .unwrap_or_default();
+
+    let port_map = inspect_result
+        .network_settings
+        .and_then(|settings| settings.ports)
+        .unwrap_or_default();
+
+    for key in port_map.keys() {
+        if !port_keys.contains(key) {
+            port_keys.push(key.clone());
         }
     }
 
-    Ok(logs)
+    let mut ports: Vec<ContainerPort> = port_keys
+        .into_iter()
+        .filter_map(|key| {
+            let (port, protocol) = key.split_once('/').unwrap_or((key.as_str(), "tcp"));
+            let port: u16 = port.parse().ok()?;
+
+            let bindings: Vec<String> = port_map
+                .get(&key)
+                .cloned()
+                .flatten()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|binding| {
+                    format!(
+                        "{}:{}",
+                        binding.host_ip.filter(|ip| !ip.is_empty()).unwrap_or_else(|| "0.0.0.0".to_string()),
+                        binding.host_port.unwrap_or_default()
+                    )
+                })
+                .collect();
+
+            Some(ContainerPort {
+                port,
+                protocol: protocol.to_string(),
+                published: !bindings.is_empty(),
+                host_binding: if bindings.is_empty() { None } else { Some(bindings.join(", ")) },
+            })
+        })
+        .collect();
+
+    ports.sort_by(|a, b| a.port.cmp(&b.port).then_with(|| a.protocol.cmp(&b.protocol)));
+
+    Ok(ports)
 }
 
+// Containers whose healthcheck is failing, either already "unhealthy" or accumulating failures
 #[tauri::command]
-async fn start_log_stream(container_id: String, app_handle: tauri::AppHandle) -> Result<String, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn unhealthy_containers(
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<UnhealthyContainer>, String> {
+    let docker = docker_client.get().await?;
+
+    // Flapping containers spend much of their time in "restarting", so keep those too
+    let candidates: Vec<ContainerInfo> = fetch_containers(&docker)
+        .await?
+        .into_iter()
+        .filter(|container| matches!(container.state.as_str(), "running" | "restarting"))
+        .collect();
 
-    let logs_options = LogsOptions::<String> {
-        stdout: true,
-        stderr: true,
-        timestamps: true,
-        tail: "all".to_string(),
-        follow: true, // This enables streaming
-        ..Default::default()
-    };
+    let inspected = futures_util::future::join_all(
+        candidates.iter().map(|container| docker.inspect_container(&container.id, None)),
+    )
+    .await;
 
-    let container_id_clone = container_id.clone();
-    let app_handle_clone = app_handle.clone();
+    let mut unhealthy = Vec::new();
+    for (container, inspect_result) in candidates.into_iter().zip(inspected) {
+        // A container may have stopped since the list was fetched
+        let Ok(inspect_result) = inspect_result else { continue };
+        let Some(health) = inspect_result.state.and_then(|state| state.health) else { continue };
 
-    // Spawn a background task to stream logs
-    tokio::spawn(async move {
-        let mut log_stream = docker.logs(&container_id_clone, Some(logs_options));
-        
-        while let Some(log_result) = log_stream.next().await {
-            match log_result {
-                Ok(log_output) => {
-                    // Convert log output to string
-                    let bytes = log_output.into_bytes();
-                    
-                    // Clean up Docker log format - remove the first 8 bytes which contain Docker headers
-                    let cleaned_log = if bytes.len() > 8 {
-                        String::from_utf8_lossy(&bytes[8..])
-                    } else {
-                        String::from_utf8_lossy(&bytes)
-                    };
-                    
-                    // Emit the log line to the frontend
-                    if let Err(e) = app_handle_clone.emit(&format!("log-stream-{}", container_id_clone), cleaned_log.to_string()) {
-                        eprintln!("Failed to emit log event: {}", e);
-                        break;
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading log stream: {}", e);
-                    // Emit error event
-                    let _ = app_handle_clone.emit(&format!("log-stream-error-{}", container_id_clone), format!("Log stream error: {}", e));
-                    break;
-                }
-            }
+        let status = health.status.map(|status| status.to_string()).unwrap_or_default();
+        let failing_streak = health.failing_streak.unwrap_or(0);
+        if status != "unhealthy" && failing_streak == 0 {
+            continue;
         }
-        
-        // Emit stream ended event
-        let _ = app_handle_clone.emit(&format!("log-stream-ended-{}", container_id_clone), "Log stream ended");
-    });
 
-    Ok("Log stream started".to_string())
-}
+        // Docker keeps the most recent probe results, newest last
+        let last_health_log = health
+            .log
+            .unwrap_or_default()
+            .into_iter()
+            .last()
+            .and_then(|result| result.output)
+            .map(|output| output.trim().to_string());
+
+        unhealthy.push(UnhealthyContainer {
+            id: container.id,
+            name: container.name,
+            health_status: status,
+            failing_streak,
+            last_health_log,
+        });
+    }
 
-#[tauri::command]
-async fn stop_log_stream(container_id: String, app_handle: tauri::AppHandle) -> Result<String, String> {
-    // Emit stop signal
-    let _ = app_handle.emit(&format!("log-stream-stop-{}", container_id), "Stream stopped");
-    Ok("Log stream stop signal sent".to_string())
+    unhealthy.sort_by(|a, b| b.failing_streak.cmp(&a.failing_streak));
+
+    Ok(unhealthy)
 }
 
 #[tauri::command]
-async fn inspect_container(container_id: String) -> Result<serde_json::Value, String> {
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+async fn validate_container_mounts(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<Vec<MountHealth>, String> {
+    let docker = docker_client.get().await?;
 
     let inspect_result = docker
         .inspect_container(&container_id, None)
         .await
         .map_err(|e| format!("Failed to inspect container: {}", e))?;
 
-    // Convert the inspect result to a JSON value for easy frontend handling
-    serde_json::to_value(inspect_result)
-        .map_err(|e| format!("Failed to serialize inspect data: {}", e))
+    let mut mount_health = Vec::new();
+
+    // Named volumes are managed by Docker, only bind mounts point at host paths
+    for mount in inspect_result.mounts.unwrap_or_default() {
+        if mount.typ != Some(bollard::models::MountPointTypeEnum::BIND) {
+            continue;
+        }
+
+        let source = mount.source.unwrap_or_default();
+        let exists = tokio::fs::try_exists(&source).await.unwrap_or(false);
+
+        mount_health.push(MountHealth {
+            source,
+            destination: mount.destination.unwrap_or_default(),
+            exists,
+            r#type: "bind".to_string(),
+        });
+    }
+
+    Ok(mount_health)
 }
 
 #[tauri::command]
 async fn exec_container_command(
     container_id: String, 
     command: Vec<String>,
-    app_handle: tauri::AppHandle
+    app_handle: tauri::AppHandle,
+    cancellation: tauri::State<'_, StreamCancellation>,
+    docker_client: tauri::State<'_, DockerClient>,
 ) -> Result<String, String> {
     use bollard::exec::{CreateExecOptions, StartExecResults};
+
+    let token = cancellation.token();
     
-    let docker = Docker::connect_with_socket_defaults()
-        .map_err(|e| format!("Failed to connect to Docker: {}", e))?;
+    let docker = docker_client.get().await?;
 
     // Build a shell command that includes the prompt and shows the command
     let shell_command = format!("echo 'root@container:~$ {}' && {}", command.join(" "), command.join(" "));
@@ -1132,7 +7024,10 @@ async fn exec_container_command(
     tokio::spawn(async move {
         match docker.start_exec(&exec_id_clone, None).await {
             Ok(StartExecResults::Attached { mut output, .. }) => {
-                while let Some(msg) = output.next().await {
+                while let Some(msg) = tokio::select! {
+                    _ = token.cancelled() => None,
+                    next = output.next() => next,
+                } {
                     match msg {
                         Ok(log_output) => {
                             let bytes = log_output.into_bytes();
@@ -1175,6 +7070,323 @@ async fn exec_container_command(
     Ok(format!("Exec session started with ID: {}", exec_id))
 }
 
+fn unix_timestamp() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+// An auto-removed container can't be waited on or inspected once it's gone, so read
+// its exit code back from the daemon's "die" event instead
+async fn exit_code_from_events(docker: &Docker, container_id: &str, since: i64) -> Option<i32> {
+    let options = bollard::system::EventsOptions::<String> {
+        since: Some(since.to_string()),
+        until: Some((unix_timestamp() + 1).to_string()),
+        filters: HashMap::from([
+            ("container".to_string(), vec![container_id.to_string()]),
+            ("event".to_string(), vec!["die".to_string()]),
+        ]),
+    };
+
+    let mut events = docker.events(Some(options));
+    while let Some(Ok(event)) = events.next().await {
+        let exit_code = event
+            .actor
+            .and_then(|actor| actor.attributes)
+            .and_then(|attributes| attributes.get("exitCode").and_then(|code| code.parse().ok()));
+        if exit_code.is_some() {
+            return exit_code;
+        }
+    }
+
+    None
+}
+
+// How long `run_oneoff` waits for the exit status once the container's output has ended
+const ONEOFF_WAIT_GRACE: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[tauri::command]
+async fn run_oneoff(
+    image: String,
+    cmd: Vec<String>,
+    env: Vec<String>,
+    credentials: tauri::State<'_, RegistryCredentials>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<TerminalOutput, String> {
+    let docker = docker_client.get().await?;
+
+    ensure_image(&docker, &image, credentials.for_image(&image)).await?;
+
+    let config = bollard::container::Config::<String> {
+        image: Some(image.clone()),
+        cmd: if cmd.is_empty() { None } else { Some(cmd) },
+        env: if env.is_empty() { None } else { Some(env) },
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        host_config: Some(bollard::models::HostConfig {
+            auto_remove: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let container = docker
+        .create_container(None::<bollard::container::CreateContainerOptions<String>>, config)
+        .await
+        .map_err(|e| format!("Failed to create container: {}", e))?;
+
+    // Attach before starting so no output is lost when the daemon removes the container on exit
+    let attach = docker
+        .attach_container(
+            &container.id,
+            Some(bollard::container::AttachContainerOptions::<String> {
+                stdout: Some(true),
+                stderr: Some(true),
+                stream: Some(true),
+                logs: Some(true),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+    let mut output = match attach {
+        Ok(attached) => attached.output,
+        Err(e) => {
+            let _ = docker
+                .remove_container(&container.id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+                .await;
+            return Err(format!("Failed to attach to container: {}", e));
+        }
+    };
+
+    // Register the wait before starting, as `docker run --rm` does: with AutoRemove the
+    // container may be gone before a wait sent after it exits reaches the daemon
+    let mut wait_task = {
+        let docker = docker.clone();
+        let container_id = container.id.clone();
+        tokio::spawn(async move { wait_for_condition(&docker, &container_id, Some("next-exit")).await })
+    };
+
+    let started_at = unix_timestamp();
+    if let Err(e) = docker
+        .start_container(&container.id, None::<bollard::container::StartContainerOptions<String>>)
+        .await
+    {
+        wait_task.abort();
+        let _ = docker
+            .remove_container(&container.id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+            .await;
+        return Err(format!("Failed to start container: {}", e));
+    }
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    while let Some(msg) = output.next().await {
+        match msg {
+            Ok(bollard::container::LogOutput::StdErr { message }) => stderr.push_str(&String::from_utf8_lossy(&message)),
+            Ok(other) => stdout.push_str(&String::from_utf8_lossy(&other.into_bytes())),
+            Err(e) => {
+                stderr.push_str(&format!("Error reading container output: {}", e));
+                break;
+            }
+        }
+    }
+
+    // Output ends when the container exits, so the wait should already be done. If it was
+    // registered too late to see the exit, fall back to the daemon's "die" event.
+    let exit_code = match tokio::time::timeout(ONEOFF_WAIT_GRACE, &mut wait_task).await {
+        Ok(Ok(Ok(code))) => Some(code as i32),
+        _ => {
+            wait_task.abort();
+            exit_code_from_events(&docker, &container.id, started_at).await
+        }
+    };
+
+    Ok(TerminalOutput {
+        stdout,
+        stderr,
+        exit_code,
+        success: exit_code == Some(0),
+    })
+}
+
+// Run a command to completion inside a running container. Without a TTY the daemon
+// multiplexes stdout and stderr, and bollard yields each frame already tagged by stream.
+#[tauri::command]
+async fn exec_in_container(
+    container_id: String,
+    cmd: Vec<String>,
+    working_dir: Option<String>,
+    env: Option<Vec<String>>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<TerminalOutput, String> {
+    use bollard::container::LogOutput;
+    use bollard::exec::{CreateExecOptions, StartExecResults};
+
+    if cmd.is_empty() {
+        return Err("Empty command".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    let exec_options = CreateExecOptions {
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        attach_stdin: Some(false),
+        tty: Some(false),
+        cmd: Some(cmd),
+        working_dir: working_dir.filter(|dir| !dir.trim().is_empty()),
+        env,
+        ..Default::default()
+    };
+
+    let exec_instance = docker
+        .create_exec(&container_id, exec_options)
+        .await
+        .map_err(|e| format!("Failed to create exec instance: {}", e))?;
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    match docker.start_exec(&exec_instance.id, None).await {
+        Ok(StartExecResults::Attached { mut output, .. }) => {
+            while let Some(frame) = output.next().await {
+                match frame.map_err(|e| format!("Failed to read exec output: {}", e))? {
+                    LogOutput::StdErr { message } => stderr.push_str(&String::from_utf8_lossy(&message)),
+                    LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                        stdout.push_str(&String::from_utf8_lossy(&message))
+                    }
+                    LogOutput::StdIn { .. } => {}
+                }
+            }
+        }
+        Ok(StartExecResults::Detached) => return Err("Exec unexpectedly started detached".to_string()),
+        Err(e) => return Err(format!("Failed to start exec: {}", e)),
+    }
+
+    let exit_code = docker
+        .inspect_exec(&exec_instance.id)
+        .await
+        .map_err(|e| format!("Failed to inspect exec: {}", e))?
+        .exit_code
+        .map(|code| code as i32);
+
+    Ok(TerminalOutput {
+        stdout,
+        stderr,
+        exit_code,
+        success: exit_code == Some(0),
+    })
+}
+
+#[tauri::command]
+async fn exec_detached(
+    container_id: String,
+    cmd: Vec<String>,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<String, String> {
+    use bollard::exec::{CreateExecOptions, StartExecOptions};
+
+    if cmd.is_empty() {
+        return Err("Empty command".to_string());
+    }
+
+    let docker = docker_client.get().await?;
+
+    let exec_options = CreateExecOptions {
+        attach_stdout: Some(false),
+        attach_stderr: Some(false),
+        attach_stdin: Some(false),
+        cmd: Some(cmd),
+        ..Default::default()
+    };
+
+    let exec_instance = docker
+        .create_exec(&container_id, exec_options)
+        .await
+        .map_err(|e| format!("Failed to create exec instance: {}", e))?;
+
+    docker
+        .start_exec(&exec_instance.id, Some(StartExecOptions { detach: true, ..Default::default() }))
+        .await
+        .map_err(|e| format!("Failed to start exec: {}", e))?;
+
+    Ok(exec_instance.id)
+}
+
+#[tauri::command]
+async fn get_exec_status(exec_id: String, docker_client: tauri::State<'_, DockerClient>) -> Result<ExecStatus, String> {
+    let docker = docker_client.get().await?;
+
+    let exec_info = docker
+        .inspect_exec(&exec_id)
+        .await
+        .map_err(|e| format!("Failed to inspect exec: {}", e))?;
+
+    Ok(ExecStatus {
+        exec_id,
+        container_id: exec_info.container_id,
+        running: exec_info.running.unwrap_or(false),
+        exit_code: exec_info.exit_code,
+    })
+}
+
+// Run `cmd` to completion inside the container and return its exit code. Errors (including a
+// missing binary, which Docker reports as 126/127) come back as None or a non-zero code.
+async fn exec_exit_code(docker: &Docker, container_id: &str, cmd: Vec<String>) -> Option<i64> {
+    use bollard::exec::{CreateExecOptions, StartExecResults};
+
+    let exec_instance = docker
+        .create_exec(container_id, CreateExecOptions {
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            cmd: Some(cmd),
+            ..Default::default()
+        })
+        .await
+        .ok()?;
+
+    if let StartExecResults::Attached { mut output, .. } = docker.start_exec(&exec_instance.id, None).await.ok()? {
+        while output.next().await.is_some() {}
+    }
+
+    docker.inspect_exec(&exec_instance.id).await.ok()?.exit_code
+}
+
+#[tauri::command]
+async fn container_exec_defaults(
+    container_id: String,
+    docker_client: tauri::State<'_, DockerClient>,
+) -> Result<ExecDefaults, String> {
+    let docker = docker_client.get().await?;
+
+    let inspect_result = docker
+        .inspect_container(&container_id, None)
+        .await
+        .map_err(|e| format!("Failed to inspect container: {}", e))?;
+
+    let config = inspect_result.config.unwrap_or_default();
+    let working_dir = config.working_dir.filter(|dir| !dir.is_empty()).unwrap_or_else(|| "/".to_string());
+    // An empty user means the image default, which is root
+    let user = config.user.filter(|user| !user.is_empty()).unwrap_or_else(|| "root".to_string());
+
+    // Minimal images (alpine, busybox) only ship /bin/sh
+    let has_bash = exec_exit_code(
+        &docker,
+        &container_id,
+        vec!["/bin/bash".to_string(), "-c".to_string(), "exit 0".to_string()],
+    )
+    .await
+        == Some(0);
+
+    Ok(ExecDefaults {
+        working_dir,
+        shell: if has_bash { "/bin/bash" } else { "/bin/sh" }.to_string(),
+        user,
+    })
+}
+
 #[tauri::command]
 async fn start_container_shell(_container_id: String, _app_handle: tauri::AppHandle) -> Result<String, String> {
     // Just return success - don't execute any initial command
@@ -1185,16 +7397,264 @@ async fn start_container_shell(_container_id: String, _app_handle: tauri::AppHan
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(Settings::default())
+        .manage(DockerClient::default())
+        .manage(StreamCancellation::default())
+        .manage(EventHistory::default())
+        .manage(DashboardFeedState::default())
+        .manage(DockerEventsFeed::default())
+        .manage(RegistryCredentials::default())
+        .manage(ImageUpdateCache::default())
+        .manage(StatsCache::default())
+        .manage(MergedLogStream::default())
+        .manage(VolumeSizeCache::default())
+        .manage(PendingPrune::default())
+        .manage(StatsStreams::default())
+        .manage(SystemMonitor::default())
+        .manage(TerminalSessions::default())
+        .manage(RunningCommands::default())
+        .manage(LogStreams::default())
+        .setup(|app| {
+            tauri::async_runtime::spawn(record_docker_events(app.handle().clone()));
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::Destroyed = event {
+                window.state::<StreamCancellation>().cancel_all();
+            }
+        })
         .invoke_handler(tauri::generate_handler![
-            greet, 
-            list_containers, start_container, stop_container, restart_container, remove_container, pause_container, unpause_container,
-            list_images, remove_image, force_remove_image,
-            list_volumes, create_volume, remove_volume, get_volume_size,
-            list_networks, remove_network,
-            execute_command, get_current_directory, get_home_directory, set_working_directory, change_directory, execute_docker_command,
-            get_system_stats, get_docker_system_info, get_container_stats, get_container_logs, start_log_stream, stop_log_stream, inspect_container,
-            exec_container_command, start_container_shell
+            greet, set_api_version,
+            list_containers, export_containers, export_container_fs, orphaned_compose_containers, find_by_port, create_container, start_container, stop_container, restart_container, restart_and_wait_healthy, remove_container, stop_and_remove_container, purge_container_and_image, prune_containers, prepare_full_prune, execute_full_prune, stopped_containers_disk, pause_container, unpause_container, signal_container, stop_all_running, pause_project, unpause_project, set_restart_policy, update_container_resources, recreate_container, pull_and_recreate,
+            list_images, pull_image, push_image, docker_login, import_image_from_url, remove_image, force_remove_image, save_image, export_oci, build_context_size, build_image, normalize_image_reference, images_based_on, check_image_updates, image_age_report,
+            list_volumes, list_volumes_fast, create_volume, remove_volume, get_volume_size, volume_usage_by_project,
+            list_networks, create_network, remove_network, reconnect_container_networks, test_network_connectivity,
+            execute_command, start_command_stream, kill_command, get_current_directory, get_home_directory, set_working_directory, change_directory, execute_docker_command,
+            get_system_stats, get_docker_system_info, get_daemon_logs, start_dashboard_feed, stop_dashboard_feed, set_poll_interval, get_poll_interval, list_nodes, get_container_stats, start_stats_stream, stop_stats_stream, export_stats_timeseries, get_all_container_stats, detailed_resource_stats, recommend_limits, get_container_logs, get_logs_page, count_log_matches, start_log_stream, stop_log_stream, start_merged_log_stream, stop_merged_log_stream, inspect_container, batch_inspect, reconstruct_run_command, get_label, categorize_labels, container_uptime, container_all_ports, validate_container_mounts, unhealthy_containers,
+            exec_container_command, run_oneoff, cancel_all_streams, get_recent_events, start_docker_events, stop_docker_events, exec_in_container, exec_detached, get_exec_status, container_exec_defaults, start_container_shell
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tar_bytes(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, data) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn parse_image_reference_accepts_valid_references() {
+        let digest = format!("sha256:{}", "a".repeat(64));
+        let with_digest = format!("localhost:5000/foo/bar@{}", digest);
+
+        let cases: Vec<(&str, &str, &str, &str, Option<&str>)> = vec![
+            ("nginx", "docker.io", "library/nginx", "latest", None),
+            ("nginx:1.27-alpine", "docker.io", "library/nginx", "1.27-alpine", None),
+            ("user/app", "docker.io", "user/app", "latest", None),
+            ("index.docker.io/user/app:v2", "docker.io", "user/app", "v2", None),
+            (with_digest.as_str(), "localhost:5000", "foo/bar", "latest", Some(digest.as_str())),
+            ("registry:5000/app:1.2", "registry:5000", "app", "1.2", None),
+            ("registry:5000/app", "registry:5000", "app", "latest", None),
+            ("ghcr.io/org/team/tool", "ghcr.io", "org/team/tool", "latest", None),
+        ];
+
+        for (input, registry, repository, tag, digest) in cases {
+            let reference = parse_image_reference(input).unwrap_or_else(|e| panic!("{}: {}", input, e));
+            assert_eq!(reference.registry, registry, "registry of {}", input);
+            assert_eq!(reference.repository, repository, "repository of {}", input);
+            assert_eq!(reference.tag, tag, "tag of {}", input);
+            assert_eq!(reference.digest.as_deref(), digest, "digest of {}", input);
+        }
+    }
+
+    #[test]
+    fn parse_image_reference_rejects_invalid_references() {
+        let cases = [
+            "",
+            "   ",
+            "NGINX",
+            "User/App",
+            "nginx:",
+            "nginx:-bad",
+            "nginx@sha256:not-hex",
+            "nginx@sha256",
+            "registry:5000/",
+            "foo//bar",
+            "foo/bar_",
+        ];
+
+        for input in cases {
+            assert!(parse_image_reference(input).is_err(), "{:?} should be rejected", input);
+        }
+    }
+
+    #[test]
+    fn strip_image_defaults_keeps_only_user_settings() {
+        let image_config = bollard::models::ContainerConfig {
+            env: Some(vec!["PATH=/usr/bin".to_string(), "APP_VERSION=1.0".to_string()]),
+            cmd: Some(vec!["serve".to_string()]),
+            working_dir: Some("/app".to_string()),
+            labels: Some(HashMap::from([("org.opencontainers.image.version".to_string(), "1.0".to_string())])),
+            ..Default::default()
+        };
+        let mut config = bollard::container::Config::<String> {
+            env: Some(vec!["PATH=/usr/bin".to_string(), "APP_VERSION=1.0".to_string(), "DEBUG=1".to_string()]),
+            cmd: Some(vec!["serve".to_string()]),
+            working_dir: Some("/srv".to_string()),
+            labels: Some(HashMap::from([
+                ("org.opencontainers.image.version".to_string(), "1.0".to_string()),
+                ("team".to_string(), "web".to_string()),
+            ])),
+            ..Default::default()
+        };
+
+        strip_image_defaults(&mut config, &image_config);
+
+        assert_eq!(config.env, Some(vec!["DEBUG=1".to_string()]));
+        assert_eq!(config.cmd, None);
+        assert_eq!(config.working_dir.as_deref(), Some("/srv"));
+        assert_eq!(config.labels, Some(HashMap::from([("team".to_string(), "web".to_string())])));
+    }
+
+    #[test]
+    fn strip_image_defaults_keeps_cmd_after_entrypoint_override() {
+        let image_config = bollard::models::ContainerConfig {
+            entrypoint: Some(vec!["/entrypoint.sh".to_string()]),
+            cmd: Some(vec!["serve".to_string()]),
+            ..Default::default()
+        };
+        let mut config = bollard::container::Config::<String> {
+            entrypoint: Some(vec!["/bin/sh".to_string()]),
+            cmd: Some(vec!["serve".to_string()]),
+            ..Default::default()
+        };
+
+        strip_image_defaults(&mut config, &image_config);
+
+        assert_eq!(config.entrypoint, Some(vec!["/bin/sh".to_string()]));
+        assert_eq!(config.cmd, Some(vec!["serve".to_string()]));
+    }
+
+    #[test]
+    fn image_pull_options_pull_a_single_tag() {
+        let cases = [
+            ("alpine", "alpine", "latest"),
+            ("alpine:3.20", "alpine", "3.20"),
+            ("localhost:5000/tools/jq", "localhost:5000/tools/jq", "latest"),
+            ("localhost:5000/tools/jq:1.7", "localhost:5000/tools/jq", "1.7"),
+        ];
+
+        for (image, from_image, tag) in cases {
+            let options = image_pull_options(image);
+            assert_eq!(options.from_image, from_image, "fromImage of {}", image);
+            assert_eq!(options.tag, tag, "tag of {}", image);
+        }
+
+        let digest = format!("sha256:{}", "b".repeat(64));
+        let options = image_pull_options(&format!("alpine@{}", digest));
+        assert_eq!(options.from_image, "alpine");
+        assert_eq!(options.tag, digest);
+    }
+
+    #[test]
+    fn docker_archive_to_oci_round_trips() {
+        use sha2::Digest;
+
+        let dir = unique_temp_dir("vessel-oci-test");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let layer = tar_bytes(&[("etc/hostname", &b"vessel\n"[..])]);
+        let config = br#"{"architecture":"amd64","os":"linux","rootfs":{"type":"layers","diff_ids":[]}}"#;
+        let manifest = br#"[{"Config":"config.json","RepoTags":["vessel/test:1.0"],"Layers":["abc/layer.tar"]}]"#;
+        let archive = tar_bytes(&[("manifest.json", &manifest[..]), ("config.json", &config[..]), ("abc/layer.tar", &layer[..])]);
+
+        let archive_path = dir.join("docker.tar");
+        let output_path = dir.join("oci.tar");
+        std::fs::write(&archive_path, archive).unwrap();
+        docker_archive_to_oci(&archive_path, &output_path, &dir.join("unpacked")).unwrap();
+
+        let layout_dir = dir.join("layout");
+        tar::Archive::new(std::fs::File::open(&output_path).unwrap()).unpack(&layout_dir).unwrap();
+
+        let read_json = |path: std::path::PathBuf| -> serde_json::Value {
+            serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+        };
+        let blob_path = |digest: &serde_json::Value| layout_dir.join("blobs/sha256").join(digest.as_str().unwrap().trim_start_matches("sha256:"));
+
+        assert_eq!(read_json(layout_dir.join("oci-layout"))["imageLayoutVersion"], "1.0.0");
+
+        let index = read_json(layout_dir.join("index.json"));
+        let entry = &index["manifests"][0];
+        assert_eq!(entry["annotations"]["org.opencontainers.image.ref.name"], "vessel/test:1.0");
+
+        let image_manifest = read_json(blob_path(&entry["digest"]));
+        assert_eq!(std::fs::read(blob_path(&image_manifest["config"]["digest"])).unwrap(), config.to_vec());
+        let layers = image_manifest["layers"].as_array().unwrap();
+        assert_eq!(layers.len(), 1);
+        assert_eq!(std::fs::read(blob_path(&layers[0]["digest"])).unwrap(), layer);
+
+        // Content addressing: every blob is named after its own sha256
+        for blob in std::fs::read_dir(layout_dir.join("blobs/sha256")).unwrap() {
+            let blob = blob.unwrap();
+            let hex = format!("{:x}", sha2::Sha256::digest(std::fs::read(blob.path()).unwrap()));
+            assert_eq!(blob.file_name().to_string_lossy(), hex);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn mount_point(
+        typ: bollard::models::MountPointTypeEnum,
+        name: Option<&str>,
+        source: &str,
+        destination: &str,
+    ) -> bollard::models::MountPoint {
+        bollard::models::MountPoint {
+            typ: Some(typ),
+            name: name.map(str::to_string),
+            source: Some(source.to_string()),
+            destination: Some(destination.to_string()),
+            rw: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn recreate_binds_preserves_anonymous_volume_identity() {
+        use bollard::models::MountPointTypeEnum;
+
+        let anonymous = "3f1c9a0e7b2d4c6f8a1b3c5d7e9f0a2b4c6d8e0f1a3b5c7d9e1f3a5b7c9d1e3f";
+        let inspect = bollard::models::ContainerInspectResponse {
+            host_config: Some(bollard::models::HostConfig {
+                binds: Some(vec!["pgdata:/var/lib/postgresql/data".to_string(), "/srv/conf:/etc/app:ro".to_string()]),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                mount_point(MountPointTypeEnum::VOLUME, Some(anonymous), "/var/lib/docker/volumes/anon/_data", "/cache"),
+                mount_point(MountPointTypeEnum::VOLUME, Some("pgdata"), "/var/lib/docker/volumes/pgdata/_data", "/var/lib/postgresql/data"),
+                mount_point(MountPointTypeEnum::BIND, None, "/srv/conf", "/etc/app"),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            recreate_binds(&inspect),
+            vec![
+                "pgdata:/var/lib/postgresql/data".to_string(),
+                "/srv/conf:/etc/app:ro".to_string(),
+                format!("{}:/cache", anonymous),
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 import React, { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
-import { ContainerInfo } from '../types/docker';
+import { ContainerInfo, LogEntry } from '../types/docker';
 import { 
   ArrowLeft,
   Square,
@@ -27,6 +27,13 @@ interface ContainerDetailProps {
 
 type TabType = 'logs' | 'inspect' | 'bindMounts' | 'exec' | 'files' | 'stats';
 
+// Renders a log entry as one text line; stderr lines are marked so they stand out
+const formatLogEntry = (entry: LogEntry): string => {
+  const prefix = entry.stream === 'stderr' ? '[stderr] ' : '';
+  const timestamp = entry.timestamp ? `${entry.timestamp} ` : '';
+  return `${timestamp}${prefix}${entry.message}\n`;
+};
+
 const ContainerDetail: React.FC<ContainerDetailProps> = ({ containerId, onBack }) => {
   const [container, setContainer] = useState<ContainerInfo | null>(null);
   const [loading, setLoading] = useState(true);
@@ -66,7 +73,7 @@ const ContainerDetail: React.FC<ContainerDetailProps> = ({ containerId, onBack }
     const setupListeners = async () => {
       // Listen for log stream events
       logUnlisten = await listen(`log-stream-${containerId}`, (event) => {
-        const newLogLine = event.payload as string;
+        const newLogLine = formatLogEntry(event.payload as LogEntry);
         setLogs(prevLogs => {
           const updated = prevLogs + newLogLine;
           // Auto-scroll if enabled
@@ -178,11 +185,12 @@ const ContainerDetail: React.FC<ContainerDetailProps> = ({ containerId, onBack }
       console.log('Loading logs for container:', containerId);
       
       // First, get historical logs
-      const containerLogs = await invoke<string>('get_container_logs', { 
+      const logEntries = await invoke<LogEntry[]>('get_container_logs', { 
         containerId: containerId,
         tail: 0, // 0 means all logs
         follow: false 
       });
+      const containerLogs = logEntries.map(formatLogEntry).join('');
       
       if (containerLogs.trim() === '') {
         setLogs('No logs available for this container.\n\nThis could mean:\n- The container hasn\'t produced any output yet\n- The container was just started\n- The application runs silently\n\nStreaming new logs...\n\n');
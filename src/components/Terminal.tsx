@@ -171,7 +171,7 @@ const Terminal: React.FC = () => {
       if (command.startsWith('cd ')) {
         const path = command.slice(3).trim();
         if (path) {
-          const result = await invoke<string>('change_directory', { path });
+          const result = await invoke<string>('change_directory', { sessionId: activeSession.id, path });
           output = {
             stdout: result,
             stderr: '',
@@ -180,7 +180,7 @@ const Terminal: React.FC = () => {
           };
           
           // Update current directory in the active session
-          const newDir = await invoke<string>('get_current_directory');
+          const newDir = await invoke<string>('get_current_directory', { sessionId: activeSession.id });
           setSessions(prev => prev.map(session => 
             session.id === activeSessionId 
               ? { ...session, currentDirectory: newDir }
@@ -215,7 +215,7 @@ const Terminal: React.FC = () => {
         output = await invoke<TerminalOutput>('execute_docker_command', { args });
       } else {
         // Execute general command
-        output = await invoke<TerminalOutput>('execute_command', { command });
+        output = await invoke<TerminalOutput>('execute_command', { sessionId: activeSession.id, command });
       }
 
       // Update the entry with the output
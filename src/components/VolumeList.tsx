@@ -102,7 +102,7 @@ const VolumeRow: React.FC<VolumeRowProps> = ({ volume, isSelected, onToggleSelec
       case 'created':
         return formatCreated(volume.created_at);
       case 'size':
-        return formatSize(volume.size);
+        return volume.size == null ? '—' : formatSize(volume.size);
       case 'driver':
         return volume.driver;
       case 'scope':
@@ -9,6 +9,7 @@ export interface ContainerInfo {
   project?: string;
   service?: string;
   labels: Record<string, string>;
+  health?: "healthy" | "unhealthy" | "starting";
 }
 
 export interface PortInfo {
@@ -36,7 +37,7 @@ export interface VolumeInfo {
   labels: Record<string, string>;
   options: Record<string, string>;
   scope: string;
-  size: number;
+  size?: number | null;
 }
 
 export interface NetworkInfo {
@@ -116,17 +117,25 @@ export interface SystemStats {
   disk_used_gb: number;
   disk_total_gb: number;
   cpu_count: number;
+  per_core_usage: number[];
+  swap_used: number;
+  swap_total: number;
+  load_average: [number, number, number] | null;
 }
 
 export interface ContainerStats {
   id: string;
   name: string;
   cpu_percentage: number;
+  cpu_mode: 'aggregate' | 'per_cpu';
   memory_usage: number;
   memory_limit: number;
   memory_percentage: number;
+  memory_usage_human: string;
+  memory_limit_human: string;
   network_rx: number;
   network_tx: number;
+  network_interfaces?: Record<string, { rx_bytes: number; tx_bytes: number }> | null;
   block_read: number;
   block_write: number;
 }
@@ -145,4 +154,10 @@ export interface ContainerProject {
   containers: ContainerInfo[];
   isExpanded: boolean;
   isSelected: boolean;
-}
\ No newline at end of file
+}
+
+export interface LogEntry {
+  timestamp: string;
+  stream: 'stdout' | 'stderr' | 'stdin' | 'system';
+  message: string;
+}